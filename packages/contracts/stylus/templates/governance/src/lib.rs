@@ -6,73 +6,140 @@
 //! This template provides a comprehensive DAO governance system with:
 //! - Proposal creation and management
 //! - Voting mechanisms (for/against/abstain)
+//! - Commit-reveal private voting
 //! - Timelock for executed proposals
+//! - Emergency proposal track with shorter periods and a supermajority
 //! - Quorum requirements
+//! - Per-target approval thresholds for protected targets
 //! - Token-based or membership-based voting power
+//! - Conviction voting for continuous treasury funding
+//! - OpenZeppelin Governor / GovernorBravo compatible interface
 
 #![cfg_attr(not(feature = "export-abi"), no_main)]
 extern crate alloc;
 
 use alloc::string::String;
 use alloc::vec::Vec;
-use alloy_primitives::{Address, U256};
+use alloy_primitives::{Address, B256, U256, U8};
 use stylus_sdk::{
-    alloy_sol_types::sol,
+    abi::Bytes,
+    alloy_sol_types::{sol, SolValue},
+    call::{call, transfer_eth, Call},
+    crypto::keccak,
     evm, msg,
     prelude::*,
-    storage::{StorageBool, StorageMap, StorageString, StorageU256, StorageVec},
+    storage::{StorageB256, StorageBool, StorageMap, StorageString, StorageU256, StorageVec},
 };
 
+/// Fixed-point scale for conviction voting parameters (1e7 = 100%)
+const CONVICTION_SCALE: u64 = 10_000_000;
+
+/// Most conviction proposals a member can hold stake on at once, which bounds
+/// the work needed to release a member's stakes
+const MAX_CONVICTION_STAKES: usize = 32;
+
+/// Governor counting mode: Bravo-style support values, for and abstain count toward quorum
+const COUNTING_MODE: &str = "support=bravo&quorum=for,abstain";
+
 // Solidity ABI for events
 sol! {
+    // Proposal events match the OpenZeppelin Governor ABI so standard
+    // indexers can track proposals without a custom decoder
     event ProposalCreated(
-        uint256 indexed proposalId,
-        address indexed proposer,
-        string description,
-        uint256 startBlock,
-        uint256 endBlock
+        uint256 proposalId,
+        address proposer,
+        address[] targets,
+        uint256[] values,
+        string[] signatures,
+        bytes[] calldatas,
+        uint256 voteStart,
+        uint256 voteEnd,
+        string description
     );
 
     event VoteCast(
         address indexed voter,
-        uint256 indexed proposalId,
+        uint256 proposalId,
         uint8 support,
         uint256 weight,
         string reason
     );
 
-    event ProposalExecuted(uint256 indexed proposalId, address indexed executor);
+    event ProposalExecuted(uint256 proposalId);
 
-    event ProposalCancelled(uint256 indexed proposalId, address indexed canceller);
+    event ProposalCanceled(uint256 proposalId);
 
-    event ProposalQueued(
-        uint256 indexed proposalId,
-        uint256 eta
-    );
+    event ProposalQueued(uint256 proposalId, uint256 etaSeconds);
+
+    event VoteCommitted(address indexed voter, uint256 indexed proposalId);
+
+    event VotingDelayUpdated(uint256 oldDelay, uint256 newDelay);
 
     event VotingPeriodUpdated(uint256 oldPeriod, uint256 newPeriod);
 
+    event RevealPeriodUpdated(uint256 oldPeriod, uint256 newPeriod);
+
     event TimelockPeriodUpdated(uint256 oldPeriod, uint256 newPeriod);
 
     event QuorumUpdated(uint256 oldQuorum, uint256 newQuorum);
 
+    event EmergencyParamsUpdated(uint256 votingPeriod, uint256 timelockPeriod, uint256 threshold);
+
+    event TargetThresholdUpdated(address indexed target, uint256 oldThreshold, uint256 newThreshold);
+
     event MemberAdded(address indexed member, uint256 votingPower);
 
     event MemberRemoved(address indexed member);
 
     event VotingPowerUpdated(address indexed member, uint256 oldPower, uint256 newPower);
+
+    event Deposit(address indexed sender, uint256 value);
+
+    event ConvictionProposalCreated(
+        uint256 indexed proposalId,
+        address indexed proposer,
+        address indexed beneficiary,
+        uint256 requestedAmount,
+        string description
+    );
+
+    event ConvictionStaked(
+        uint256 indexed proposalId,
+        address indexed member,
+        uint256 amount,
+        uint256 conviction
+    );
+
+    event ConvictionWithdrawn(
+        uint256 indexed proposalId,
+        address indexed member,
+        uint256 amount,
+        uint256 conviction
+    );
+
+    event ConvictionProposalExecuted(
+        uint256 indexed proposalId,
+        address indexed beneficiary,
+        uint256 amount,
+        uint256 conviction
+    );
+
+    event ConvictionProposalCancelled(uint256 indexed proposalId, address indexed canceller);
+
+    event ConvictionParamsUpdated(uint256 decay, uint256 maxRatio, uint256 weight);
 }
 
-// Proposal state enum
+// Proposal state enum (values match Governor's ProposalState; 6 = Expired is unused)
 #[derive(Copy, Clone, PartialEq)]
 pub enum ProposalState {
     Pending = 0,
     Active = 1,
-    Succeeded = 2,
+    Cancelled = 2,
     Defeated = 3,
-    Queued = 4,
-    Executed = 5,
-    Cancelled = 6,
+    Succeeded = 4,
+    Queued = 5,
+    Executed = 7,
+    Revealing = 8,
 }
 
 // Vote type enum
@@ -119,6 +186,18 @@ sol_storage! {
         uint256 abstain_votes;
         StorageMap<Address, VoteRecord> votes;
 
+        // Snapshotted at creation so later config changes can't flip the outcome
+        uint256 approval_threshold;
+        uint256 total_voting_power;
+
+        // Emergency track
+        bool emergency;
+
+        // Commit-reveal voting
+        bool commit_reveal;
+        uint256 reveal_end_block;
+        StorageMap<Address, StorageB256> commitments;
+
         // Execution
         uint256 eta; // Estimated time of execution (after timelock)
         bool executed;
@@ -129,19 +208,55 @@ sol_storage! {
     }
 }
 
+// Conviction voting proposal structure
+sol_storage! {
+    pub struct ConvictionProposal {
+        uint256 id;
+        address proposer;
+        address beneficiary;
+        uint256 requested_amount;
+        StorageString description;
+
+        // Staking and conviction
+        uint256 staked;
+        uint256 conviction; // as of last_update_block
+        uint256 last_update_block;
+        StorageMap<Address, U256> stakes;
+
+        // Execution
+        bool executed;
+        bool cancelled;
+
+        // Metadata
+        uint256 created_at;
+    }
+}
+
 // Storage layout for the governance contract
 sol_storage! {
     #[entrypoint]
     pub struct DAOGovernance {
         // Governance parameters
         StorageString name;
+        StorageU256 voting_delay; // in blocks
         StorageU256 voting_period; // in blocks
+        StorageU256 reveal_period; // in blocks, for commit-reveal proposals
         StorageU256 timelock_period; // in seconds
         StorageU256 quorum; // minimum votes required (percentage * 100, e.g., 4000 = 40%)
         StorageU256 proposal_threshold; // minimum voting power to create proposal
 
+        // Emergency track parameters
+        StorageU256 emergency_voting_period; // in blocks
+        StorageU256 emergency_timelock_period; // in seconds
+        StorageU256 emergency_threshold; // share of cast votes required (percentage * 100)
+
+        // Approval thresholds for protected targets: share of for/against
+        // votes required (percentage * 100), zero means simple majority
+        StorageMap<Address, U256> target_thresholds;
+
         // Proposals
         StorageVec<Proposal> proposals;
+        StorageMap<U256, U256> proposal_index; // proposal ID (hashProposal) -> index + 1
         StorageU256 proposal_count;
 
         // Members and voting power
@@ -150,6 +265,14 @@ sol_storage! {
         StorageVec<Address> members;
         StorageU256 total_voting_power;
 
+        // Conviction voting
+        StorageVec<ConvictionProposal> conviction_proposals;
+        StorageMap<Address, U256> conviction_staked; // total stake per member across proposals
+        StorageMap<Address, StorageVec<U256>> staked_proposals; // proposals each member has stake on
+        StorageU256 conviction_decay; // per-block retention, scaled by 1e7
+        StorageU256 conviction_max_ratio; // max requestable treasury share, scaled by 1e7
+        StorageU256 conviction_weight; // threshold weight, scaled by 1e7
+
         // Governance controls
         StorageBool initialized;
         StorageAddress admin;
@@ -189,15 +312,35 @@ impl DAOGovernance {
 
         // Set parameters
         self.name.set_str(&name);
+        self.voting_delay.set(U256::ZERO);
         self.voting_period.set(voting_period);
+        self.reveal_period.set(voting_period);
         self.timelock_period.set(timelock_period);
         self.quorum.set(quorum);
         self.proposal_threshold.set(proposal_threshold);
         self.admin.set(admin);
+
+        // Emergency track defaults: a quarter of the voting period,
+        // no timelock and a 66% supermajority of cast votes
+        let emergency_voting_period = voting_period / U256::from(4);
+        self.emergency_voting_period.set(if emergency_voting_period.is_zero() {
+            U256::from(1)
+        } else {
+            emergency_voting_period
+        });
+        self.emergency_timelock_period.set(U256::ZERO);
+        self.emergency_threshold.set(U256::from(6600));
         self.paused.set(false);
         self.proposal_count.set(U256::ZERO);
         self.total_voting_power.set(U256::ZERO);
 
+        // Conviction voting defaults: ~3 day half-life (21,600 blocks; on
+        // Arbitrum the block number is an L1 estimate advancing every ~12s),
+        // at most 20% of the treasury per request, 0.5% threshold weight
+        self.conviction_decay.set(U256::from(9_999_679));
+        self.conviction_max_ratio.set(U256::from(2_000_000));
+        self.conviction_weight.set(U256::from(50_000));
+
         // Mark as initialized
         self.initialized.set(true);
 
@@ -209,11 +352,21 @@ impl DAOGovernance {
         Ok(self.name.get_string())
     }
 
+    /// Get voting delay (in blocks)
+    pub fn get_voting_delay(&self) -> Result<U256, Vec<u8>> {
+        Ok(self.voting_delay.get())
+    }
+
     /// Get voting period (in blocks)
     pub fn get_voting_period(&self) -> Result<U256, Vec<u8>> {
         Ok(self.voting_period.get())
     }
 
+    /// Get reveal period for commit-reveal proposals (in blocks)
+    pub fn get_reveal_period(&self) -> Result<U256, Vec<u8>> {
+        Ok(self.reveal_period.get())
+    }
+
     /// Get timelock period (in seconds)
     pub fn get_timelock_period(&self) -> Result<U256, Vec<u8>> {
         Ok(self.timelock_period.get())
@@ -311,6 +464,9 @@ impl DAOGovernance {
         // Get member's voting power
         let power = self.voting_power.get(member);
 
+        // Removed members keep no conviction stakes
+        self.release_conviction_stakes(member, U256::ZERO)?;
+
         // Remove member
         let member_count = self.members.len();
         for i in 0..member_count {
@@ -359,6 +515,9 @@ impl DAOGovernance {
 
         let old_power = self.voting_power.get(member);
 
+        // Stakes may not exceed the reduced voting power
+        self.release_conviction_stakes(member, new_power)?;
+
         // Update voting power
         self.voting_power.setter(member).set(new_power);
 
@@ -379,6 +538,21 @@ impl DAOGovernance {
         Ok(())
     }
 
+    /// Update voting delay (admin only)
+    pub fn set_voting_delay(&mut self, new_delay: U256) -> Result<(), Vec<u8>> {
+        self.require_admin()?;
+
+        let old_delay = self.voting_delay.get();
+        self.voting_delay.set(new_delay);
+
+        evm::log(VotingDelayUpdated {
+            oldDelay: old_delay,
+            newDelay: new_delay,
+        });
+
+        Ok(())
+    }
+
     /// Update voting period (admin only)
     pub fn set_voting_period(&mut self, new_period: U256) -> Result<(), Vec<u8>> {
         self.require_admin()?;
@@ -387,6 +561,11 @@ impl DAOGovernance {
             return Err(b"Invalid voting period".to_vec());
         }
 
+        // The emergency track may never be slower than the normal one
+        if new_period < self.emergency_voting_period.get() {
+            return Err(b"Voting period below emergency voting period".to_vec());
+        }
+
         let old_period = self.voting_period.get();
         self.voting_period.set(new_period);
 
@@ -398,10 +577,34 @@ impl DAOGovernance {
         Ok(())
     }
 
+    /// Update reveal period for commit-reveal proposals (admin only)
+    pub fn set_reveal_period(&mut self, new_period: U256) -> Result<(), Vec<u8>> {
+        self.require_admin()?;
+
+        if new_period.is_zero() {
+            return Err(b"Invalid reveal period".to_vec());
+        }
+
+        let old_period = self.reveal_period.get();
+        self.reveal_period.set(new_period);
+
+        evm::log(RevealPeriodUpdated {
+            oldPeriod: old_period,
+            newPeriod: new_period,
+        });
+
+        Ok(())
+    }
+
     /// Update timelock period (admin only)
     pub fn set_timelock_period(&mut self, new_period: U256) -> Result<(), Vec<u8>> {
         self.require_admin()?;
 
+        // The emergency track may never be slower than the normal one
+        if new_period < self.emergency_timelock_period.get() {
+            return Err(b"Timelock period below emergency timelock period".to_vec());
+        }
+
         let old_period = self.timelock_period.get();
         self.timelock_period.set(new_period);
 
@@ -432,6 +635,528 @@ impl DAOGovernance {
         Ok(())
     }
 
+    /// Create a proposal with public voting
+    ///
+    /// The returned ID is `hashProposal(targets, values, calldatas, keccak256(description))`.
+    pub fn propose(
+        &mut self,
+        targets: Vec<Address>,
+        values: Vec<U256>,
+        calldatas: Vec<Bytes>,
+        description: String,
+    ) -> Result<U256, Vec<u8>> {
+        self._propose(targets, values, calldatas, description, false, false)
+    }
+
+    /// Create a proposal with commit-reveal voting
+    ///
+    /// Members commit hidden votes while the proposal is active and reveal
+    /// them during the reveal period that follows. Unrevealed commits count
+    /// toward neither the tally nor quorum.
+    pub fn propose_commit_reveal(
+        &mut self,
+        targets: Vec<Address>,
+        values: Vec<U256>,
+        calldatas: Vec<Bytes>,
+        description: String,
+    ) -> Result<U256, Vec<u8>> {
+        self._propose(targets, values, calldatas, description, true, false)
+    }
+
+    /// Create an emergency proposal
+    ///
+    /// Emergency proposals use the shorter emergency voting and timelock
+    /// periods and must reach the emergency supermajority of cast votes
+    /// in addition to quorum.
+    pub fn propose_emergency(
+        &mut self,
+        targets: Vec<Address>,
+        values: Vec<U256>,
+        calldatas: Vec<Bytes>,
+        description: String,
+    ) -> Result<U256, Vec<u8>> {
+        self._propose(targets, values, calldatas, description, false, true)
+    }
+
+    /// Cast a vote (0 = Against, 1 = For, 2 = Abstain)
+    pub fn cast_vote(&mut self, proposal_id: U256, support: u8) -> Result<U256, Vec<u8>> {
+        self.cast_vote_with_reason(proposal_id, support, String::new())
+    }
+
+    /// Cast a vote with a reason
+    pub fn cast_vote_with_reason(
+        &mut self,
+        proposal_id: U256,
+        support: u8,
+        reason: String,
+    ) -> Result<U256, Vec<u8>> {
+        self.require_member()?;
+        self.require_not_paused()?;
+
+        if self.proposal_state(proposal_id)? != ProposalState::Active {
+            return Err(b"Proposal not active".to_vec());
+        }
+
+        if self.get_proposal_ref(proposal_id)?.commit_reveal.get() {
+            return Err(b"Proposal uses commit-reveal voting".to_vec());
+        }
+
+        self._cast_vote(proposal_id, msg::sender(), support, reason)
+    }
+
+    /// Commit a hidden vote on a commit-reveal proposal
+    ///
+    /// The commitment is keccak256(abi.encodePacked(uint8 support, bytes32 salt, address voter)).
+    /// Committing again before voting ends replaces the previous commitment.
+    pub fn commit_vote(&mut self, proposal_id: U256, commitment: B256) -> Result<(), Vec<u8>> {
+        self.require_member()?;
+        self.require_not_paused()?;
+
+        if self.proposal_state(proposal_id)? != ProposalState::Active {
+            return Err(b"Proposal not active".to_vec());
+        }
+
+        if !self.get_proposal_ref(proposal_id)?.commit_reveal.get() {
+            return Err(b"Proposal uses public voting".to_vec());
+        }
+
+        if commitment == B256::ZERO {
+            return Err(b"Invalid commitment".to_vec());
+        }
+
+        let voter = msg::sender();
+        let proposal = self.get_proposal_mut(proposal_id)?;
+        proposal.commitments.setter(voter).set(commitment);
+
+        evm::log(VoteCommitted {
+            voter,
+            proposalId: proposal_id,
+        });
+
+        Ok(())
+    }
+
+    /// Reveal a previously committed vote during the reveal period
+    pub fn reveal_vote(
+        &mut self,
+        proposal_id: U256,
+        support: u8,
+        salt: B256,
+    ) -> Result<U256, Vec<u8>> {
+        self.require_member()?;
+        self.require_not_paused()?;
+
+        if self.proposal_state(proposal_id)? != ProposalState::Revealing {
+            return Err(b"Proposal not in reveal period".to_vec());
+        }
+
+        let voter = msg::sender();
+        let commitment = self.get_proposal_ref(proposal_id)?.commitments.get(voter);
+        if commitment == B256::ZERO {
+            return Err(b"No vote committed".to_vec());
+        }
+
+        if commitment != self.vote_commitment(support, salt, voter) {
+            return Err(b"Commitment mismatch".to_vec());
+        }
+
+        {
+            let proposal = self.get_proposal_mut(proposal_id)?;
+            proposal.commitments.setter(voter).set(B256::ZERO);
+        }
+
+        self._cast_vote(proposal_id, voter, support, String::new())
+    }
+
+    /// Compute the commitment for a commit-reveal vote
+    pub fn compute_vote_commitment(
+        &self,
+        support: u8,
+        salt: B256,
+        voter: Address,
+    ) -> Result<B256, Vec<u8>> {
+        Ok(self.vote_commitment(support, salt, voter))
+    }
+
+    /// Get a voter's pending commitment on a proposal
+    pub fn get_vote_commitment(&self, proposal_id: U256, voter: Address) -> Result<B256, Vec<u8>> {
+        Ok(self.get_proposal_ref(proposal_id)?.commitments.get(voter))
+    }
+
+    /// Get proposal state as a Governor `ProposalState`
+    ///
+    /// Commit-reveal proposals in their reveal period are reported as Active.
+    pub fn state(&self, proposal_id: U256) -> Result<u8, Vec<u8>> {
+        match self.proposal_state(proposal_id)? {
+            ProposalState::Revealing => Ok(ProposalState::Active as u8),
+            state => Ok(state as u8),
+        }
+    }
+
+    /// Get proposal state including the commit-reveal phase (8 = Revealing)
+    pub fn get_proposal_state(&self, proposal_id: U256) -> Result<u8, Vec<u8>> {
+        Ok(self.proposal_state(proposal_id)? as u8)
+    }
+
+    /// Queue a succeeded proposal for execution after the timelock
+    pub fn queue(&mut self, proposal_id: U256) -> Result<(), Vec<u8>> {
+        self.require_not_paused()?;
+
+        if self.proposal_state(proposal_id)? != ProposalState::Succeeded {
+            return Err(b"Proposal not succeeded".to_vec());
+        }
+
+        let timelock_period = if self.get_proposal_ref(proposal_id)?.emergency.get() {
+            self.emergency_timelock_period.get()
+        } else {
+            self.timelock_period.get()
+        };
+
+        let eta = self.current_timestamp() + timelock_period;
+        let proposal = self.get_proposal_mut(proposal_id)?;
+        proposal.eta.set(eta);
+
+        evm::log(ProposalQueued {
+            proposalId: proposal_id,
+            etaSeconds: eta,
+        });
+
+        Ok(())
+    }
+
+    /// Execute a queued proposal once its timelock has passed
+    #[payable]
+    pub fn execute(&mut self, proposal_id: U256) -> Result<(), Vec<u8>> {
+        self.require_not_paused()?;
+
+        if self.proposal_state(proposal_id)? != ProposalState::Queued {
+            return Err(b"Proposal not queued".to_vec());
+        }
+
+        if self.current_timestamp() < self.get_proposal_ref(proposal_id)?.eta.get() {
+            return Err(b"Timelock not expired".to_vec());
+        }
+
+        // Mark as executed before external calls (reentrancy protection)
+        {
+            let proposal = self.get_proposal_mut(proposal_id)?;
+            proposal.executed.set(true);
+        }
+
+        let (targets, values, calldatas) = self.proposal_actions(proposal_id)?;
+        for i in 0..targets.len() {
+            let call_result = unsafe {
+                call(
+                    Call::new_in(self)
+                        .value(values[i]),
+                    targets[i],
+                    &calldatas[i],
+                )
+            };
+
+            if call_result.is_err() {
+                return Err(b"Proposal action failed".to_vec());
+            }
+        }
+
+        evm::log(ProposalExecuted {
+            proposalId: proposal_id,
+        });
+
+        Ok(())
+    }
+
+    /// Cancel a proposal that has not been executed (proposer or admin only)
+    pub fn cancel(&mut self, proposal_id: U256) -> Result<(), Vec<u8>> {
+        let state = self.proposal_state(proposal_id)?;
+        if state == ProposalState::Executed || state == ProposalState::Cancelled {
+            return Err(b"Proposal cannot be cancelled".to_vec());
+        }
+
+        let canceller = msg::sender();
+        let proposer = self.get_proposal_ref(proposal_id)?.proposer.get();
+        if canceller != proposer && canceller != self.admin.get() {
+            return Err(b"Caller cannot cancel proposal".to_vec());
+        }
+
+        let proposal = self.get_proposal_mut(proposal_id)?;
+        proposal.cancelled.set(true);
+
+        evm::log(ProposalCanceled {
+            proposalId: proposal_id,
+        });
+
+        Ok(())
+    }
+
+    /// Queue a proposal identified by its contents (Governor `queue`)
+    #[selector(name = "queue")]
+    pub fn queue_by_hash(
+        &mut self,
+        targets: Vec<Address>,
+        values: Vec<U256>,
+        calldatas: Vec<Bytes>,
+        description_hash: B256,
+    ) -> Result<U256, Vec<u8>> {
+        let proposal_id = self.proposal_id(&targets, &values, &calldatas, description_hash);
+        self.queue(proposal_id)?;
+        Ok(proposal_id)
+    }
+
+    /// Execute a proposal identified by its contents (Governor `execute`)
+    #[payable]
+    #[selector(name = "execute")]
+    pub fn execute_by_hash(
+        &mut self,
+        targets: Vec<Address>,
+        values: Vec<U256>,
+        calldatas: Vec<Bytes>,
+        description_hash: B256,
+    ) -> Result<U256, Vec<u8>> {
+        let proposal_id = self.proposal_id(&targets, &values, &calldatas, description_hash);
+        self.execute(proposal_id)?;
+        Ok(proposal_id)
+    }
+
+    /// Cancel a proposal identified by its contents (Governor `cancel`)
+    #[selector(name = "cancel")]
+    pub fn cancel_by_hash(
+        &mut self,
+        targets: Vec<Address>,
+        values: Vec<U256>,
+        calldatas: Vec<Bytes>,
+        description_hash: B256,
+    ) -> Result<U256, Vec<u8>> {
+        let proposal_id = self.proposal_id(&targets, &values, &calldatas, description_hash);
+        self.cancel(proposal_id)?;
+        Ok(proposal_id)
+    }
+
+    /// Compute a proposal ID the same way as Governor's `hashProposal`
+    pub fn hash_proposal(
+        &self,
+        targets: Vec<Address>,
+        values: Vec<U256>,
+        calldatas: Vec<Bytes>,
+        description_hash: B256,
+    ) -> Result<U256, Vec<u8>> {
+        Ok(self.proposal_id(&targets, &values, &calldatas, description_hash))
+    }
+
+    /// Governor name
+    pub fn name(&self) -> Result<String, Vec<u8>> {
+        Ok(self.name.get_string())
+    }
+
+    /// Governor version
+    pub fn version(&self) -> Result<String, Vec<u8>> {
+        Ok(String::from("1"))
+    }
+
+    /// Governor counting mode
+    #[selector(name = "COUNTING_MODE")]
+    pub fn counting_mode(&self) -> Result<String, Vec<u8>> {
+        Ok(String::from(COUNTING_MODE))
+    }
+
+    /// Governor voting delay (in blocks)
+    pub fn voting_delay(&self) -> Result<U256, Vec<u8>> {
+        Ok(self.voting_delay.get())
+    }
+
+    /// Governor voting period (in blocks)
+    pub fn voting_period(&self) -> Result<U256, Vec<u8>> {
+        Ok(self.voting_period.get())
+    }
+
+    /// Governor quorum as an absolute number of votes
+    ///
+    /// Voting power is not checkpointed, so this uses the current total
+    /// voting power regardless of `block_number`.
+    pub fn quorum(&self, _block_number: U256) -> Result<U256, Vec<u8>> {
+        Ok(self.quorum.get() * self.total_voting_power.get() / U256::from(10000))
+    }
+
+    /// Governor proposal threshold
+    pub fn proposal_threshold(&self) -> Result<U256, Vec<u8>> {
+        Ok(self.proposal_threshold.get())
+    }
+
+    /// Governor voting power of an account
+    ///
+    /// Voting power is not checkpointed, so this returns the current power
+    /// regardless of `block_number`.
+    pub fn get_votes(&self, account: Address, _block_number: U256) -> Result<U256, Vec<u8>> {
+        Ok(self.voting_power.get(account))
+    }
+
+    /// Governor vote tally (against, for, abstain)
+    pub fn proposal_votes(&self, proposal_id: U256) -> Result<(U256, U256, U256), Vec<u8>> {
+        let proposal = self.get_proposal_ref(proposal_id)?;
+        Ok((
+            proposal.against_votes.get(),
+            proposal.for_votes.get(),
+            proposal.abstain_votes.get(),
+        ))
+    }
+
+    /// Governor proposal snapshot (block voting starts)
+    pub fn proposal_snapshot(&self, proposal_id: U256) -> Result<U256, Vec<u8>> {
+        Ok(self.get_proposal_ref(proposal_id)?.start_block.get())
+    }
+
+    /// Governor proposal deadline (block voting ends, including any reveal period)
+    pub fn proposal_deadline(&self, proposal_id: U256) -> Result<U256, Vec<u8>> {
+        let proposal = self.get_proposal_ref(proposal_id)?;
+        if proposal.commit_reveal.get() {
+            return Ok(proposal.reveal_end_block.get());
+        }
+        Ok(proposal.end_block.get())
+    }
+
+    /// Governor proposal proposer
+    pub fn proposal_proposer(&self, proposal_id: U256) -> Result<Address, Vec<u8>> {
+        Ok(self.get_proposal_ref(proposal_id)?.proposer.get())
+    }
+
+    /// Governor proposal eta (timestamp the proposal becomes executable)
+    pub fn proposal_eta(&self, proposal_id: U256) -> Result<U256, Vec<u8>> {
+        Ok(self.get_proposal_ref(proposal_id)?.eta.get())
+    }
+
+    /// Governor check whether an account has voted
+    pub fn has_voted(&self, proposal_id: U256, account: Address) -> Result<bool, Vec<u8>> {
+        let proposal = self.get_proposal_ref(proposal_id)?;
+        Ok(proposal.votes.get(account).has_voted.get())
+    }
+
+    /// Get proposal details
+    /// Returns (proposer, start block, end block, for, against, abstain, eta, commit-reveal, emergency)
+    pub fn get_proposal(
+        &self,
+        proposal_id: U256,
+    ) -> Result<(Address, U256, U256, U256, U256, U256, U256, bool, bool), Vec<u8>> {
+        let proposal = self.get_proposal_ref(proposal_id)?;
+
+        Ok((
+            proposal.proposer.get(),
+            proposal.start_block.get(),
+            proposal.end_block.get(),
+            proposal.for_votes.get(),
+            proposal.against_votes.get(),
+            proposal.abstain_votes.get(),
+            proposal.eta.get(),
+            proposal.commit_reveal.get(),
+            proposal.emergency.get(),
+        ))
+    }
+
+    /// Get proposal description
+    pub fn get_proposal_description(&self, proposal_id: U256) -> Result<String, Vec<u8>> {
+        Ok(self.get_proposal_ref(proposal_id)?.description.get_string())
+    }
+
+    /// Get proposal actions (targets, values, calldatas)
+    pub fn get_proposal_actions(
+        &self,
+        proposal_id: U256,
+    ) -> Result<(Vec<Address>, Vec<U256>, Vec<Bytes>), Vec<u8>> {
+        let (targets, values, calldatas) = self.proposal_actions(proposal_id)?;
+        Ok((targets, values, calldatas.into_iter().map(Bytes::from).collect()))
+    }
+
+    /// Get a voter's receipt (has voted, support, weight)
+    pub fn get_receipt(
+        &self,
+        proposal_id: U256,
+        voter: Address,
+    ) -> Result<(bool, u8, U256), Vec<u8>> {
+        let proposal = self.get_proposal_ref(proposal_id)?;
+        let record = proposal.votes.get(voter);
+
+        Ok((
+            record.has_voted.get(),
+            record.vote_type.get().to::<u8>(),
+            record.weight.get(),
+        ))
+    }
+
+    /// Get emergency track parameters (voting period, timelock period, threshold)
+    pub fn get_emergency_params(&self) -> Result<(U256, U256, U256), Vec<u8>> {
+        Ok((
+            self.emergency_voting_period.get(),
+            self.emergency_timelock_period.get(),
+            self.emergency_threshold.get(),
+        ))
+    }
+
+    /// Update emergency track parameters (admin or governance only)
+    pub fn set_emergency_params(
+        &mut self,
+        voting_period: U256,
+        timelock_period: U256,
+        threshold: U256,
+    ) -> Result<(), Vec<u8>> {
+        self.require_governance()?;
+
+        if voting_period.is_zero() || voting_period > self.voting_period.get() {
+            return Err(b"Invalid emergency voting period".to_vec());
+        }
+
+        if timelock_period > self.timelock_period.get() {
+            return Err(b"Invalid emergency timelock period".to_vec());
+        }
+
+        if threshold <= U256::from(5000) || threshold > U256::from(10000) {
+            return Err(b"Invalid emergency threshold".to_vec());
+        }
+
+        self.emergency_voting_period.set(voting_period);
+        self.emergency_timelock_period.set(timelock_period);
+        self.emergency_threshold.set(threshold);
+
+        evm::log(EmergencyParamsUpdated {
+            votingPeriod: voting_period,
+            timelockPeriod: timelock_period,
+            threshold,
+        });
+
+        Ok(())
+    }
+
+    /// Get approval threshold for a target (percentage * 100, zero means simple majority)
+    pub fn get_target_threshold(&self, target: Address) -> Result<U256, Vec<u8>> {
+        Ok(self.target_thresholds.get(target))
+    }
+
+    /// Get the approval threshold a proposal must meet, the highest across its action targets
+    pub fn get_proposal_approval_threshold(&self, proposal_id: U256) -> Result<U256, Vec<u8>> {
+        Ok(self.get_proposal_ref(proposal_id)?.approval_threshold.get())
+    }
+
+    /// Set approval threshold for a target (admin or governance only)
+    ///
+    /// Any proposal created afterwards with an action hitting `target` needs at
+    /// least this share of for/against votes to succeed. Pass zero to clear.
+    pub fn set_target_threshold(&mut self, target: Address, threshold: U256) -> Result<(), Vec<u8>> {
+        self.require_governance()?;
+
+        if !threshold.is_zero() && (threshold <= U256::from(5000) || threshold > U256::from(10000)) {
+            return Err(b"Invalid approval threshold".to_vec());
+        }
+
+        let old_threshold = self.target_thresholds.get(target);
+        self.target_thresholds.setter(target).set(threshold);
+
+        evm::log(TargetThresholdUpdated {
+            target,
+            oldThreshold: old_threshold,
+            newThreshold: threshold,
+        });
+
+        Ok(())
+    }
+
     /// Get admin address
     pub fn get_admin(&self) -> Result<Address, Vec<u8>> {
         Ok(self.admin.get())
@@ -477,6 +1202,292 @@ impl DAOGovernance {
         self.admin.set(new_admin);
         Ok(())
     }
+
+    /// Deposit ETH into the treasury
+    #[payable]
+    pub fn deposit(&self) -> Result<(), Vec<u8>> {
+        evm::log(Deposit {
+            sender: msg::sender(),
+            value: msg::value(),
+        });
+        Ok(())
+    }
+
+    /// Get treasury balance
+    pub fn get_treasury_balance(&self) -> Result<U256, Vec<u8>> {
+        Ok(self.treasury_balance())
+    }
+
+    /// Create a conviction voting funding request
+    pub fn create_conviction_proposal(
+        &mut self,
+        beneficiary: Address,
+        requested_amount: U256,
+        description: String,
+    ) -> Result<U256, Vec<u8>> {
+        self.require_member()?;
+        self.require_not_paused()?;
+
+        let proposer = msg::sender();
+        if self.voting_power.get(proposer) < self.proposal_threshold.get() {
+            return Err(b"Insufficient voting power to propose".to_vec());
+        }
+
+        if beneficiary == Address::ZERO {
+            return Err(b"Invalid beneficiary address".to_vec());
+        }
+
+        if requested_amount.is_zero() {
+            return Err(b"Requested amount must be positive".to_vec());
+        }
+
+        let proposal_id = U256::from(self.conviction_proposals.len());
+        let current_block = self.current_block();
+        let created_at = self.current_timestamp();
+
+        let mut proposal = self.conviction_proposals.grow();
+        proposal.id.set(proposal_id);
+        proposal.proposer.set(proposer);
+        proposal.beneficiary.set(beneficiary);
+        proposal.requested_amount.set(requested_amount);
+        proposal.description.set_str(&description);
+        proposal.staked.set(U256::ZERO);
+        proposal.conviction.set(U256::ZERO);
+        proposal.last_update_block.set(current_block);
+        proposal.executed.set(false);
+        proposal.cancelled.set(false);
+        proposal.created_at.set(created_at);
+
+        evm::log(ConvictionProposalCreated {
+            proposalId: proposal_id,
+            proposer,
+            beneficiary,
+            requestedAmount: requested_amount,
+            description,
+        });
+
+        Ok(proposal_id)
+    }
+
+    /// Stake voting power on a conviction proposal
+    pub fn stake_conviction(&mut self, proposal_id: U256, amount: U256) -> Result<(), Vec<u8>> {
+        self.require_member()?;
+        self.require_not_paused()?;
+
+        if amount.is_zero() {
+            return Err(b"Stake amount must be positive".to_vec());
+        }
+
+        let member = msg::sender();
+        let total_staked = self.conviction_staked.get(member);
+        if total_staked + amount > self.voting_power.get(member) {
+            return Err(b"Insufficient unstaked voting power".to_vec());
+        }
+
+        self.require_conviction_open(proposal_id)?;
+        let conviction = self.update_conviction(proposal_id)?;
+
+        let stake = self.get_conviction_proposal_ref(proposal_id)?.stakes.get(member);
+        if stake.is_zero() {
+            if self.staked_proposals.get(member).len() >= MAX_CONVICTION_STAKES {
+                return Err(b"Too many conviction stakes".to_vec());
+            }
+            self.staked_proposals.setter(member).push(proposal_id);
+        }
+
+        let proposal = self.get_conviction_proposal_mut(proposal_id)?;
+        proposal.stakes.setter(member).set(stake + amount);
+        let staked = proposal.staked.get();
+        proposal.staked.set(staked + amount);
+
+        self.conviction_staked.setter(member).set(total_staked + amount);
+
+        evm::log(ConvictionStaked {
+            proposalId: proposal_id,
+            member,
+            amount,
+            conviction,
+        });
+
+        Ok(())
+    }
+
+    /// Withdraw stake from a conviction proposal
+    ///
+    /// Stake can always be withdrawn, including from executed or cancelled
+    /// proposals, so members can free their voting power for other requests.
+    pub fn withdraw_conviction(&mut self, proposal_id: U256, amount: U256) -> Result<(), Vec<u8>> {
+        let member = msg::sender();
+
+        let stake = self.get_conviction_proposal_ref(proposal_id)?.stakes.get(member);
+        if amount.is_zero() || amount > stake {
+            return Err(b"Invalid withdraw amount".to_vec());
+        }
+
+        let conviction = self.update_conviction(proposal_id)?;
+
+        let proposal = self.get_conviction_proposal_mut(proposal_id)?;
+        proposal.stakes.setter(member).set(stake - amount);
+        let staked = proposal.staked.get();
+        proposal.staked.set(staked - amount);
+
+        let total_staked = self.conviction_staked.get(member);
+        self.conviction_staked.setter(member).set(total_staked - amount);
+
+        if stake == amount {
+            self.untrack_conviction_stake(member, proposal_id);
+        }
+
+        evm::log(ConvictionWithdrawn {
+            proposalId: proposal_id,
+            member,
+            amount,
+            conviction,
+        });
+
+        Ok(())
+    }
+
+    /// Execute a conviction proposal once its conviction passes the threshold
+    pub fn execute_conviction_proposal(&mut self, proposal_id: U256) -> Result<(), Vec<u8>> {
+        self.require_not_paused()?;
+        self.require_conviction_open(proposal_id)?;
+
+        let conviction = self.update_conviction(proposal_id)?;
+
+        let (beneficiary, amount) = {
+            let proposal = self.get_conviction_proposal_ref(proposal_id)?;
+            (proposal.beneficiary.get(), proposal.requested_amount.get())
+        };
+
+        let threshold = self.conviction_threshold(amount)?;
+        if conviction < threshold {
+            return Err(b"Conviction below threshold".to_vec());
+        }
+
+        // Mark as executed before transfer (reentrancy protection)
+        {
+            let proposal = self.get_conviction_proposal_mut(proposal_id)?;
+            proposal.executed.set(true);
+        }
+
+        transfer_eth(beneficiary, amount)?;
+
+        evm::log(ConvictionProposalExecuted {
+            proposalId: proposal_id,
+            beneficiary,
+            amount,
+            conviction,
+        });
+
+        Ok(())
+    }
+
+    /// Cancel a conviction proposal (proposer or admin only)
+    pub fn cancel_conviction_proposal(&mut self, proposal_id: U256) -> Result<(), Vec<u8>> {
+        self.require_conviction_open(proposal_id)?;
+
+        let canceller = msg::sender();
+        let proposer = self.get_conviction_proposal_ref(proposal_id)?.proposer.get();
+        if canceller != proposer && canceller != self.admin.get() {
+            return Err(b"Caller cannot cancel proposal".to_vec());
+        }
+
+        let proposal = self.get_conviction_proposal_mut(proposal_id)?;
+        proposal.cancelled.set(true);
+
+        evm::log(ConvictionProposalCancelled {
+            proposalId: proposal_id,
+            canceller,
+        });
+
+        Ok(())
+    }
+
+    /// Get conviction proposal details
+    /// Returns (proposer, beneficiary, requested amount, staked, conviction, executed, cancelled)
+    pub fn get_conviction_proposal(
+        &self,
+        proposal_id: U256,
+    ) -> Result<(Address, Address, U256, U256, U256, bool, bool), Vec<u8>> {
+        let proposal = self.get_conviction_proposal_ref(proposal_id)?;
+        let conviction = self.current_conviction(proposal);
+
+        Ok((
+            proposal.proposer.get(),
+            proposal.beneficiary.get(),
+            proposal.requested_amount.get(),
+            proposal.staked.get(),
+            conviction,
+            proposal.executed.get(),
+            proposal.cancelled.get(),
+        ))
+    }
+
+    /// Get total conviction proposal count
+    pub fn get_conviction_proposal_count(&self) -> Result<U256, Vec<u8>> {
+        Ok(U256::from(self.conviction_proposals.len()))
+    }
+
+    /// Get conviction required for a proposal to execute at the current treasury balance
+    pub fn get_conviction_threshold(&self, proposal_id: U256) -> Result<U256, Vec<u8>> {
+        let amount = self.get_conviction_proposal_ref(proposal_id)?.requested_amount.get();
+        self.conviction_threshold(amount)
+    }
+
+    /// Get a member's stake on a conviction proposal
+    pub fn get_conviction_stake(&self, proposal_id: U256, member: Address) -> Result<U256, Vec<u8>> {
+        Ok(self.get_conviction_proposal_ref(proposal_id)?.stakes.get(member))
+    }
+
+    /// Get a member's total stake across all conviction proposals
+    pub fn get_conviction_staked(&self, member: Address) -> Result<U256, Vec<u8>> {
+        Ok(self.conviction_staked.get(member))
+    }
+
+    /// Get conviction voting parameters (decay, max ratio, weight), scaled by 1e7
+    pub fn get_conviction_params(&self) -> Result<(U256, U256, U256), Vec<u8>> {
+        Ok((
+            self.conviction_decay.get(),
+            self.conviction_max_ratio.get(),
+            self.conviction_weight.get(),
+        ))
+    }
+
+    /// Update conviction voting parameters (admin only)
+    pub fn set_conviction_params(
+        &mut self,
+        decay: U256,
+        max_ratio: U256,
+        weight: U256,
+    ) -> Result<(), Vec<u8>> {
+        self.require_admin()?;
+
+        let scale = U256::from(CONVICTION_SCALE);
+        if decay.is_zero() || decay >= scale {
+            return Err(b"Invalid conviction decay".to_vec());
+        }
+
+        if max_ratio.is_zero() || max_ratio > scale {
+            return Err(b"Invalid conviction max ratio".to_vec());
+        }
+
+        if weight.is_zero() {
+            return Err(b"Invalid conviction weight".to_vec());
+        }
+
+        self.conviction_decay.set(decay);
+        self.conviction_max_ratio.set(max_ratio);
+        self.conviction_weight.set(weight);
+
+        evm::log(ConvictionParamsUpdated {
+            decay,
+            maxRatio: max_ratio,
+            weight,
+        });
+
+        Ok(())
+    }
 }
 
 /// Internal helper methods
@@ -489,6 +1500,16 @@ impl DAOGovernance {
         Ok(())
     }
 
+    /// Require that caller is admin or the governance contract itself
+    /// (i.e. the call comes from an executed proposal)
+    fn require_governance(&self) -> Result<(), Vec<u8>> {
+        let sender = msg::sender();
+        if sender != self.admin.get() && sender != Address::from(evm::contract_address().0) {
+            return Err(b"Caller is not governance".to_vec());
+        }
+        Ok(())
+    }
+
     /// Require that contract is not paused
     fn require_not_paused(&self) -> Result<(), Vec<u8>> {
         if self.paused.get() {
@@ -505,6 +1526,299 @@ impl DAOGovernance {
         Ok(())
     }
 
+    /// Internal method to create a proposal
+    fn _propose(
+        &mut self,
+        targets: Vec<Address>,
+        values: Vec<U256>,
+        calldatas: Vec<Bytes>,
+        description: String,
+        commit_reveal: bool,
+        emergency: bool,
+    ) -> Result<U256, Vec<u8>> {
+        self.require_member()?;
+        self.require_not_paused()?;
+
+        let proposer = msg::sender();
+        if self.voting_power.get(proposer) < self.proposal_threshold.get() {
+            return Err(b"Insufficient voting power to propose".to_vec());
+        }
+
+        if targets.is_empty() {
+            return Err(b"Actions required".to_vec());
+        }
+
+        if targets.len() != values.len() || targets.len() != calldatas.len() {
+            return Err(b"Action length mismatch".to_vec());
+        }
+
+        let description_hash = keccak(description.as_bytes());
+        let proposal_id = self.proposal_id(&targets, &values, &calldatas, description_hash);
+        if !self.proposal_index.get(proposal_id).is_zero() {
+            return Err(b"Proposal already exists".to_vec());
+        }
+
+        let start_block = self.current_block() + self.voting_delay.get();
+        let voting_period = if emergency {
+            self.emergency_voting_period.get()
+        } else {
+            self.voting_period.get()
+        };
+        let end_block = start_block + voting_period;
+        let reveal_end_block = if commit_reveal {
+            end_block + self.reveal_period.get()
+        } else {
+            U256::ZERO
+        };
+        let created_at = self.current_timestamp();
+        let approval_threshold = self.approval_threshold(&targets);
+        let total_voting_power = self.total_voting_power.get();
+
+        let mut proposal = self.proposals.grow();
+        proposal.id.set(proposal_id);
+        proposal.proposer.set(proposer);
+        proposal.description.set_str(&description);
+
+        for i in 0..targets.len() {
+            let mut action = proposal.actions.grow();
+            action.target.set(targets[i]);
+            action.value.set(values[i]);
+            for byte in calldatas[i].iter() {
+                action.calldata.push(*byte);
+            }
+        }
+
+        proposal.start_block.set(start_block);
+        proposal.end_block.set(end_block);
+        proposal.approval_threshold.set(approval_threshold);
+        proposal.total_voting_power.set(total_voting_power);
+        proposal.emergency.set(emergency);
+        proposal.commit_reveal.set(commit_reveal);
+        proposal.reveal_end_block.set(reveal_end_block);
+        proposal.executed.set(false);
+        proposal.cancelled.set(false);
+        proposal.created_at.set(created_at);
+
+        // Stored as index + 1 so that zero means "no proposal"
+        let index_plus_one = U256::from(self.proposals.len());
+        self.proposal_index.setter(proposal_id).set(index_plus_one);
+        self.proposal_count.set(self.proposal_count.get() + U256::from(1));
+
+        evm::log(ProposalCreated {
+            proposalId: proposal_id,
+            proposer,
+            signatures: targets.iter().map(|_| String::new()).collect(),
+            calldatas: calldatas.into_iter().map(|c| c.0.into()).collect(),
+            targets,
+            values,
+            voteStart: start_block,
+            voteEnd: end_block,
+            description,
+        });
+
+        Ok(proposal_id)
+    }
+
+    /// Internal method to record a vote and update the tally
+    fn _cast_vote(
+        &mut self,
+        proposal_id: U256,
+        voter: Address,
+        support: u8,
+        reason: String,
+    ) -> Result<U256, Vec<u8>> {
+        if support > VoteType::Abstain as u8 {
+            return Err(b"Invalid vote type".to_vec());
+        }
+
+        let weight = self.voting_power.get(voter);
+
+        let proposal = self.get_proposal_mut(proposal_id)?;
+        if proposal.votes.get(voter).has_voted.get() {
+            return Err(b"Already voted".to_vec());
+        }
+
+        let mut record = proposal.votes.setter(voter);
+        record.has_voted.set(true);
+        record.vote_type.set(U8::from(support));
+        record.weight.set(weight);
+
+        if support == VoteType::For as u8 {
+            let votes = proposal.for_votes.get();
+            proposal.for_votes.set(votes + weight);
+        } else if support == VoteType::Against as u8 {
+            let votes = proposal.against_votes.get();
+            proposal.against_votes.set(votes + weight);
+        } else {
+            let votes = proposal.abstain_votes.get();
+            proposal.abstain_votes.set(votes + weight);
+        }
+
+        evm::log(VoteCast {
+            voter,
+            proposalId: proposal_id,
+            support,
+            weight,
+            reason,
+        });
+
+        Ok(weight)
+    }
+
+    /// Resolve the current state of a proposal
+    fn proposal_state(&self, proposal_id: U256) -> Result<ProposalState, Vec<u8>> {
+        let proposal = self.get_proposal_ref(proposal_id)?;
+
+        if proposal.cancelled.get() {
+            return Ok(ProposalState::Cancelled);
+        }
+
+        if proposal.executed.get() {
+            return Ok(ProposalState::Executed);
+        }
+
+        // Success was settled when the proposal was queued
+        if !proposal.eta.get().is_zero() {
+            return Ok(ProposalState::Queued);
+        }
+
+        let current_block = self.current_block();
+        if current_block < proposal.start_block.get() {
+            return Ok(ProposalState::Pending);
+        }
+
+        if current_block <= proposal.end_block.get() {
+            return Ok(ProposalState::Active);
+        }
+
+        if proposal.commit_reveal.get() && current_block <= proposal.reveal_end_block.get() {
+            return Ok(ProposalState::Revealing);
+        }
+
+        if !self.vote_succeeded(proposal) {
+            return Ok(ProposalState::Defeated);
+        }
+
+        Ok(ProposalState::Succeeded)
+    }
+
+    /// Check whether a proposal reached quorum with more for than against votes
+    ///
+    /// For and abstain votes count toward quorum of the total voting power
+    /// at creation. Proposals touching a protected target must reach the
+    /// approval threshold snapshotted at creation, and emergency proposals
+    /// must also reach the emergency threshold as a share of all cast votes.
+    fn vote_succeeded(&self, proposal: &Proposal) -> bool {
+        let for_votes = proposal.for_votes.get();
+        let against_votes = proposal.against_votes.get();
+        let abstain_votes = proposal.abstain_votes.get();
+        let participation = for_votes + abstain_votes;
+
+        let quorum_reached = participation * U256::from(10000)
+            >= self.quorum.get() * proposal.total_voting_power.get();
+
+        if !quorum_reached || for_votes <= against_votes {
+            return false;
+        }
+
+        let approval_threshold = proposal.approval_threshold.get();
+        if !approval_threshold.is_zero()
+            && for_votes * U256::from(10000) < approval_threshold * (for_votes + against_votes)
+        {
+            return false;
+        }
+
+        if proposal.emergency.get() {
+            let cast_votes = for_votes + against_votes + abstain_votes;
+            return for_votes * U256::from(10000) >= self.emergency_threshold.get() * cast_votes;
+        }
+
+        true
+    }
+
+    /// Highest approval threshold across a set of action targets
+    fn approval_threshold(&self, targets: &[Address]) -> U256 {
+        let mut threshold = U256::ZERO;
+        for target in targets {
+            let target_threshold = self.target_thresholds.get(*target);
+            if target_threshold > threshold {
+                threshold = target_threshold;
+            }
+        }
+        threshold
+    }
+
+    /// Hash a commit-reveal vote
+    fn vote_commitment(&self, support: u8, salt: B256, voter: Address) -> B256 {
+        let mut preimage = Vec::with_capacity(53);
+        preimage.push(support);
+        preimage.extend_from_slice(salt.as_slice());
+        preimage.extend_from_slice(voter.as_slice());
+        keccak(preimage)
+    }
+
+    /// Governor-compatible proposal ID:
+    /// keccak256(abi.encode(targets, values, calldatas, descriptionHash))
+    fn proposal_id(
+        &self,
+        targets: &[Address],
+        values: &[U256],
+        calldatas: &[Bytes],
+        description_hash: B256,
+    ) -> U256 {
+        let calldatas: Vec<alloy_primitives::Bytes> =
+            calldatas.iter().map(|c| c.0.clone().into()).collect();
+        let encoded = (targets.to_vec(), values.to_vec(), calldatas, description_hash)
+            .abi_encode_params();
+        U256::from_be_bytes(keccak(encoded).0)
+    }
+
+    /// Read a proposal's actions from storage
+    fn proposal_actions(
+        &self,
+        proposal_id: U256,
+    ) -> Result<(Vec<Address>, Vec<U256>, Vec<Vec<u8>>), Vec<u8>> {
+        let proposal = self.get_proposal_ref(proposal_id)?;
+
+        let mut targets = Vec::new();
+        let mut values = Vec::new();
+        let mut calldatas = Vec::new();
+        for i in 0..proposal.actions.len() {
+            if let Some(action) = proposal.actions.get(i) {
+                targets.push(action.target.get());
+                values.push(action.value.get());
+
+                let mut calldata = Vec::new();
+                for j in 0..action.calldata.len() {
+                    if let Some(byte) = action.calldata.get(j) {
+                        calldata.push(byte);
+                    }
+                }
+                calldatas.push(calldata);
+            }
+        }
+
+        Ok((targets, values, calldatas))
+    }
+
+    /// Get proposal reference (immutable)
+    fn get_proposal_ref(&self, proposal_id: U256) -> Result<&Proposal, Vec<u8>> {
+        let index = self.proposal_index.get(proposal_id);
+        if index.is_zero() {
+            return Err(b"Proposal does not exist".to_vec());
+        }
+        Ok(self.proposals.getter(index.to::<usize>() - 1).unwrap())
+    }
+
+    /// Get proposal reference (mutable)
+    fn get_proposal_mut(&mut self, proposal_id: U256) -> Result<&mut Proposal, Vec<u8>> {
+        let index = self.proposal_index.get(proposal_id);
+        if index.is_zero() {
+            return Err(b"Proposal does not exist".to_vec());
+        }
+        Ok(self.proposals.setter(index.to::<usize>() - 1).unwrap())
+    }
+
     /// Get current block number
     fn current_block(&self) -> U256 {
         U256::from(evm::block_number())
@@ -514,4 +1828,187 @@ impl DAOGovernance {
     fn current_timestamp(&self) -> U256 {
         U256::from(evm::block_timestamp())
     }
+
+    /// Get treasury balance
+    fn treasury_balance(&self) -> U256 {
+        U256::from(Address::from(evm::contract_address().0).balance())
+    }
+
+    /// Require that conviction proposal exists and is neither executed nor cancelled
+    fn require_conviction_open(&self, proposal_id: U256) -> Result<(), Vec<u8>> {
+        let proposal = self.get_conviction_proposal_ref(proposal_id)?;
+        if proposal.executed.get() {
+            return Err(b"Proposal already executed".to_vec());
+        }
+        if proposal.cancelled.get() {
+            return Err(b"Proposal cancelled".to_vec());
+        }
+        Ok(())
+    }
+
+    /// Conviction accrued up to the current block, without writing it back
+    ///
+    /// Follows y_t = y_0 * a^t + x * (1 - a^t) / (1 - a), where `a` is the
+    /// per-block decay and `x` is the stake held since the last update.
+    fn current_conviction(&self, proposal: &ConvictionProposal) -> U256 {
+        let last_update = proposal.last_update_block.get();
+        let current_block = self.current_block();
+        if current_block <= last_update {
+            return proposal.conviction.get();
+        }
+
+        let scale = U256::from(CONVICTION_SCALE);
+        let decay = self.conviction_decay.get();
+        let decay_t = self.decay_pow(decay, current_block - last_update);
+
+        let retained = proposal.conviction.get() * decay_t / scale;
+        let accrued = proposal.staked.get() * (scale - decay_t) / (scale - decay);
+        retained + accrued
+    }
+
+    /// Withdraw a member's conviction stakes, most recently staked proposals
+    /// first, until at most `max_staked` remains staked
+    ///
+    /// Only the member's tracked proposals are visited, so the cost is bounded
+    /// by `MAX_CONVICTION_STAKES` rather than the number of proposals.
+    fn release_conviction_stakes(&mut self, member: Address, max_staked: U256) -> Result<(), Vec<u8>> {
+        let total_staked = self.conviction_staked.get(member);
+        if total_staked <= max_staked {
+            return Ok(());
+        }
+
+        let mut excess = total_staked - max_staked;
+        let mut index = self.staked_proposals.get(member).len();
+        while !excess.is_zero() && index > 0 {
+            index -= 1;
+            let proposal_id = match self.staked_proposals.get(member).get(index) {
+                Some(proposal_id) => proposal_id,
+                None => continue,
+            };
+
+            let stake = self.get_conviction_proposal_ref(proposal_id)?.stakes.get(member);
+
+            let amount = stake.min(excess);
+            let conviction = self.update_conviction(proposal_id)?;
+
+            let proposal = self.get_conviction_proposal_mut(proposal_id)?;
+            proposal.stakes.setter(member).set(stake - amount);
+            let staked = proposal.staked.get();
+            proposal.staked.set(staked - amount);
+
+            excess -= amount;
+
+            if stake == amount {
+                self.untrack_conviction_stake(member, proposal_id);
+            }
+
+            evm::log(ConvictionWithdrawn {
+                proposalId: proposal_id,
+                member,
+                amount,
+                conviction,
+            });
+        }
+
+        self.conviction_staked.setter(member).set(max_staked + excess);
+
+        Ok(())
+    }
+
+    /// Forget a proposal a member no longer has stake on
+    fn untrack_conviction_stake(&mut self, member: Address, proposal_id: U256) {
+        let mut staked_proposals = self.staked_proposals.setter(member);
+        let count = staked_proposals.len();
+        for i in 0..count {
+            if staked_proposals.get(i) == Some(proposal_id) {
+                // Swap with last element and pop
+                if i < count - 1 {
+                    if let Some(last) = staked_proposals.get(count - 1) {
+                        staked_proposals.setter(i).unwrap().set(last);
+                    }
+                }
+                staked_proposals.pop();
+                break;
+            }
+        }
+    }
+
+    /// Bring a conviction proposal's stored conviction up to the current block
+    fn update_conviction(&mut self, proposal_id: U256) -> Result<U256, Vec<u8>> {
+        let conviction = {
+            let proposal = self.get_conviction_proposal_ref(proposal_id)?;
+            self.current_conviction(proposal)
+        };
+        let current_block = self.current_block();
+
+        let proposal = self.get_conviction_proposal_mut(proposal_id)?;
+        proposal.conviction.set(conviction);
+        proposal.last_update_block.set(current_block);
+
+        Ok(conviction)
+    }
+
+    /// Conviction needed to release `requested_amount` from the treasury
+    ///
+    /// threshold = weight * S / (1 - a) / (max_ratio - requested / funds)^2,
+    /// where S is the total voting power. Requests at or above the max ratio
+    /// of the treasury can never pass.
+    fn conviction_threshold(&self, requested_amount: U256) -> Result<U256, Vec<u8>> {
+        let funds = self.treasury_balance();
+        if funds.is_zero() {
+            return Err(b"Treasury is empty".to_vec());
+        }
+
+        let scale = U256::from(CONVICTION_SCALE);
+        let max_ratio = self.conviction_max_ratio.get();
+        let requested_ratio = requested_amount * scale / funds;
+        if requested_ratio >= max_ratio {
+            return Err(b"Requested amount exceeds max ratio".to_vec());
+        }
+
+        let denominator = max_ratio - requested_ratio;
+        let decay = self.conviction_decay.get();
+
+        Ok(self.conviction_weight.get() * self.total_voting_power.get() * scale * scale
+            / ((scale - decay) * denominator * denominator))
+    }
+
+    /// Raise a scaled decay factor to the power of `blocks`
+    fn decay_pow(&self, decay: U256, blocks: U256) -> U256 {
+        let scale = U256::from(CONVICTION_SCALE);
+        let mut result = scale;
+        let mut base = decay;
+        let mut exponent = blocks;
+
+        while !exponent.is_zero() {
+            if exponent.bit(0) {
+                result = result * base / scale;
+            }
+            base = base * base / scale;
+            exponent >>= 1;
+        }
+
+        result
+    }
+
+    /// Get conviction proposal reference (immutable)
+    fn get_conviction_proposal_ref(&self, proposal_id: U256) -> Result<&ConvictionProposal, Vec<u8>> {
+        let index = proposal_id.to::<usize>();
+        if index >= self.conviction_proposals.len() {
+            return Err(b"Proposal does not exist".to_vec());
+        }
+        Ok(self.conviction_proposals.getter(index).unwrap())
+    }
+
+    /// Get conviction proposal reference (mutable)
+    fn get_conviction_proposal_mut(
+        &mut self,
+        proposal_id: U256,
+    ) -> Result<&mut ConvictionProposal, Vec<u8>> {
+        let index = proposal_id.to::<usize>();
+        if index >= self.conviction_proposals.len() {
+            return Err(b"Proposal does not exist".to_vec());
+        }
+        Ok(self.conviction_proposals.setter(index).unwrap())
+    }
 }
@@ -3,44 +3,241 @@
 
 //! Multisig Wallet Template for Stylus
 //!
-//! This template provides a comprehensive multisig wallet implementation
-//! that requires multiple owner confirmations to execute transactions.
+//! This template provides a comprehensive multisig wallet with:
+//! - Weighted owners and a required confirmation weight
+//! - On-chain confirmations or off-chain EIP-712 signatures
+//! - Batch transactions, expiry and cancellation
+//! - Per-token rolling 24-hour spending limits for single-owner payments
+//! - Optional execution delay for revoking confirmations
+//! - Confirmation policies for transfers, calls and owner management
+//! - Modules that execute without confirmations
+//! - Optional guard to veto transactions and assert post-conditions
+//! - ERC-1271 signature validation and ERC-721/ERC-1155 receiving
+//! - Guardian social recovery with an owner veto
+//! - Contract deployment via CREATE or CREATE2
+//! - Recurring payment schedules
 
 #![cfg_attr(not(feature = "export-abi"), no_main)]
 extern crate alloc;
 
 use alloc::vec::Vec;
-use alloy_primitives::{Address, U256};
+use alloy_primitives::{Address, FixedBytes, B256, U256, U8};
 use stylus_sdk::{
-    alloy_sol_types::sol,
-    call::{call, Call},
-    evm, msg,
+    abi::Bytes,
+    alloy_sol_types::{sol, SolError, SolValue},
+    block,
+    call::{call, static_call, Call},
+    crypto::keccak,
+    deploy::RawDeploy,
+    evm, function_selector, msg,
     prelude::*,
-    storage::{StorageBool, StorageMap, StorageU256, StorageVec},
+    storage::{StorageAddress, StorageBool, StorageMap, StorageU256, StorageVec},
 };
 
+/// EIP-712 domain type, bound to the chain and the wallet address
+const DOMAIN_TYPE: &[u8] = b"EIP712Domain(uint256 chainId,address verifyingContract)";
+
+/// EIP-712 type for transactions executed with off-chain signatures
+const TRANSACTION_TYPE: &[u8] = b"MultisigTransaction(address to,uint256 value,bytes data,uint256 nonce)";
+
+/// EIP-712 type for messages signed on behalf of the wallet (ERC-1271)
+const MESSAGE_TYPE: &[u8] = b"MultisigMessage(bytes32 hash)";
+
+/// ERC-1271 magic value returned for valid signatures (`isValidSignature.selector`)
+const ERC1271_MAGIC_VALUE: [u8; 4] = [0x16, 0x26, 0xba, 0x7e];
+
+/// ERC-1271 value returned for invalid signatures
+const ERC1271_INVALID: [u8; 4] = [0xff, 0xff, 0xff, 0xff];
+
+/// `onERC721Received.selector`, also the ERC721TokenReceiver interface ID
+const ERC721_RECEIVED: [u8; 4] = [0x15, 0x0b, 0x7a, 0x02];
+
+/// `onERC1155Received.selector`
+const ERC1155_RECEIVED: [u8; 4] = [0xf2, 0x3a, 0x6e, 0x61];
+
+/// `onERC1155BatchReceived.selector`
+const ERC1155_BATCH_RECEIVED: [u8; 4] = [0xbc, 0x19, 0x7c, 0x81];
+
+/// ERC-165 interface IDs reported by `supports_interface`
+const ERC165_INTERFACE_ID: [u8; 4] = [0x01, 0xff, 0xc9, 0xa7];
+const ERC1155_RECEIVER_INTERFACE_ID: [u8; 4] = [0x4e, 0x23, 0x12, 0xe0];
+
+/// Length of an ECDSA signature encoded as r (32) || s (32) || v (1)
+const SIGNATURE_LENGTH: usize = 65;
+
+/// Length of the rolling spending limit window in seconds (24 hours)
+const SPENDING_LIMIT_WINDOW: u64 = 86_400;
+
+/// Shortest recovery delay allowed while recovery is enabled (24 hours),
+/// so owners always get a window to veto
+const MIN_RECOVERY_DELAY: u64 = 86_400;
+
+/// Gas kept back for the wallet's own work around a gas-limited call
+const CALL_GAS_BUFFER: u64 = 10_000;
+
+/// Upper bound for `s` in a non-malleable signature (secp256k1n / 2)
+const SECP256K1_HALF_N: U256 = U256::from_limbs([
+    0xdfe92f46681b20a0,
+    0x5d576e7357a4501d,
+    0xffffffffffffffff,
+    0x7fffffffffffffff,
+]);
+
 // Solidity ABI for events
 sol! {
     event OwnerAdded(address indexed owner);
     event OwnerRemoved(address indexed owner);
     event RequirementChanged(uint256 required);
+    event OwnerWeightChanged(address indexed owner, uint256 weight);
     event TransactionSubmitted(uint256 indexed txId, address indexed submitter, address indexed to, uint256 value);
+    event BatchSubmitted(uint256 indexed txId, uint256 callCount);
+    event ContractDeployed(uint256 indexed txId, address indexed deployed);
     event TransactionConfirmed(uint256 indexed txId, address indexed owner);
     event ConfirmationRevoked(uint256 indexed txId, address indexed owner);
-    event TransactionExecuted(uint256 indexed txId, address indexed executor);
-    event TransactionFailed(uint256 indexed txId, address indexed executor);
+    event TransactionExecuted(uint256 indexed txId, address indexed executor, bytes32 returnDataHash);
+    event TransactionExpired(uint256 indexed txId, uint256 validUntil);
+    event CancellationConfirmed(uint256 indexed txId, address indexed owner);
+    event TransactionCancelled(uint256 indexed txId, address indexed canceller);
     event Deposit(address indexed sender, uint256 value);
+    event SignedTransactionExecuted(uint256 indexed nonce, bytes32 indexed txHash, address indexed executor, bytes32 returnDataHash);
+    event SpendingLimitChanged(address indexed token, uint256 amount);
+    event ExecutionDelayChanged(uint256 delay);
+    event TransactionReady(uint256 indexed txId, uint256 readyAt);
+    event EnabledModule(address indexed module);
+    event DisabledModule(address indexed module);
+    event ExecutionFromModule(address indexed module, address indexed to, uint256 value);
+    event ChangedGuard(address indexed guard);
+    event SignMessage(bytes32 indexed hash);
+    event GuardianAdded(address indexed guardian);
+    event GuardianRemoved(address indexed guardian);
+    event GuardianThresholdChanged(uint256 threshold);
+    event RecoveryDelayChanged(uint256 delay);
+    event RecoveryProposed(uint256 indexed recoveryId, address indexed guardian);
+    event RecoveryConfirmed(uint256 indexed recoveryId, address indexed guardian);
+    event RecoveryReady(uint256 indexed recoveryId, uint256 readyAt);
+    event RecoveryVetoConfirmed(uint256 indexed recoveryId, address indexed owner);
+    event RecoveryVetoed(uint256 indexed recoveryId);
+    event RecoveryExecuted(uint256 indexed recoveryId, address indexed executor);
+    event ConfirmationPolicyChanged(uint256 transferCap, uint256 transferThreshold, uint256 callThreshold, uint256 ownerManagementThreshold);
+    event SpendingLimitUsed(address indexed token, address indexed to, address indexed owner, uint256 amount);
+    event PaymentScheduleCreated(uint256 indexed scheduleId, address indexed recipient, address indexed token, uint256 amount, uint256 interval, uint256 start, uint256 end);
+    event PaymentScheduleCancelled(uint256 indexed scheduleId);
+    event ScheduledPaymentExecuted(uint256 indexed scheduleId, uint256 indexed period, address indexed recipient, address token, uint256 amount);
+}
+
+// Solidity ABI for errors
+sol! {
+    error BatchCallFailed(uint256 index, bytes reason);
+}
+
+// Transaction guard interface; either hook reverts to veto the transaction
+sol_interface! {
+    interface ITransactionGuard {
+        function checkTransaction(address to, uint256 value, bytes data, address executor) external;
+        function checkAfterExecution(address to, uint256 value, bytes data, address executor) external;
+    }
+}
+
+// Transaction status enum
+#[derive(Copy, Clone, PartialEq)]
+pub enum TransactionStatus {
+    Pending = 0,
+    Executed = 1,
+    Expired = 2,
+    Cancelled = 3,
+}
+
+// Transaction kind enum
+#[derive(Copy, Clone, PartialEq)]
+pub enum TransactionKind {
+    Call = 0,
+    Batch = 1,
+    Create = 2,
+    Create2 = 3,
+}
+
+// Single call within a batch transaction
+sol_storage! {
+    pub struct BatchCall {
+        address destination;
+        uint256 value;
+        StorageVec<u8> data;
+    }
 }
 
 // Transaction structure
 sol_storage! {
     pub struct Transaction {
+        uint8 kind; // 0 = Call, 1 = Batch, 2 = Create, 3 = Create2
         address destination;
         uint256 value;
         StorageVec<u8> data;
+        StorageVec<BatchCall> calls; // batch transactions only
         bool executed;
         StorageMap<Address, bool> confirmations;
-        uint256 confirmation_count;
+        uint256 confirmation_count; // includes confirmations from removed owners
+        uint256 valid_until; // timestamp, zero means no deadline
+        bool expired;
+
+        // Cancellation
+        address submitter;
+        bool cancelled;
+        StorageMap<Address, bool> cancellations;
+
+        // Execution delay
+        uint256 ready_at; // timestamp execution is allowed, zero until fully confirmed
+        uint256 ready_nonce; // config nonce when ready_at was set
+
+        // Hash of the data returned by execution, zero until executed
+        bytes32 return_data_hash;
+
+        uint256 gas_limit; // gas forwarded to each call, zero forwards all remaining gas
+
+        // Contract deployment (data holds the init code)
+        bytes32 salt; // Create2 only
+        address deployed; // zero until executed
+    }
+}
+
+// Rolling 24 hour spending limit for a token (zero address = native ETH)
+sol_storage! {
+    pub struct SpendingLimit {
+        uint256 amount; // allowance per rolling window
+        StorageVec<U256> spend_times; // timestamp of each spend
+        StorageVec<U256> spend_amounts; // amount of each spend
+        uint256 first_active; // index of the oldest spend that may still be in the window
+    }
+}
+
+// Owner set proposed by guardians to recover the wallet
+sol_storage! {
+    pub struct RecoveryRequest {
+        StorageVec<Address> owners;
+        StorageVec<U256> weights;
+        uint256 required;
+        uint256 recovery_nonce; // wallet recovery nonce when proposed
+        StorageMap<Address, bool> approvals;
+        uint256 ready_at; // timestamp execution is allowed, zero until approved
+
+        // Veto by current owners
+        StorageMap<Address, bool> vetoes;
+        bool vetoed;
+
+        bool executed;
+    }
+}
+
+// Recurring payment of a fixed amount (zero token address = native ETH)
+sol_storage! {
+    pub struct PaymentSchedule {
+        address recipient;
+        address token;
+        uint256 amount; // paid per installment
+        uint256 interval; // seconds between installments
+        uint256 start; // timestamp the first installment is due
+        uint256 end; // no installment falls due after this timestamp, zero means no end
+        uint256 paid_periods; // installments paid so far
+        bool cancelled;
     }
 }
 
@@ -51,11 +248,53 @@ sol_storage! {
         // Owner management
         StorageVec<Address> owners;
         StorageMap<Address, bool> is_owner;
-        StorageU256 required_confirmations;
+        StorageMap<Address, U256> owner_weights;
+        StorageU256 total_weight;
+        StorageU256 required_confirmations; // total confirmation weight required
+
+        // Confirmation policies; a zero threshold falls back to required_confirmations
+        StorageU256 transfer_cap; // largest ETH transfer covered by transfer_threshold
+        StorageU256 transfer_threshold; // plain ETH transfers up to transfer_cap
+        StorageU256 call_threshold; // calls to other contracts and larger transfers
+        StorageU256 owner_management_threshold; // self-calls that change wallet control
+
+        // Delay in seconds between final confirmation and execution
+        StorageU256 execution_delay;
+
+        // Bumped whenever owners, weights, thresholds or the delay change,
+        // invalidating execution delays started under the old configuration
+        StorageU256 config_nonce;
 
         // Transaction management
         StorageVec<Transaction> transactions;
 
+        // Nonce for transactions executed with off-chain signatures
+        StorageU256 nonce;
+
+        // Daily spending limits by token (zero address = native ETH)
+        StorageMap<Address, SpendingLimit> spending_limits;
+
+        // Recurring payment schedules
+        StorageVec<PaymentSchedule> payment_schedules;
+
+        // Modules allowed to execute without confirmations
+        StorageVec<Address> modules;
+        StorageMap<Address, bool> is_module;
+
+        // Guard checked around every confirmed or signed call (zero = none)
+        StorageAddress guard;
+
+        // Message hashes approved on-chain for ERC-1271 validation
+        StorageMap<B256, bool> signed_messages;
+
+        // Social recovery
+        StorageVec<Address> guardians;
+        StorageMap<Address, bool> is_guardian;
+        StorageU256 guardian_threshold; // guardian approvals required, zero disables recovery
+        StorageU256 recovery_delay; // seconds between guardian approval and execution
+        StorageVec<RecoveryRequest> recoveries;
+        StorageU256 recovery_nonce; // bumped by every executed recovery
+
         // Initialization
         StorageBool initialized;
     }
@@ -64,9 +303,13 @@ sol_storage! {
 #[external]
 impl MultisigWallet {
     /// Initialize the multisig wallet (called once by the deployer)
+    ///
+    /// `required` is the total owner weight needed to confirm a transaction.
+    /// Give every owner a weight of 1 for a plain M-of-N wallet.
     pub fn initialize(
         &mut self,
         owners: Vec<Address>,
+        weights: Vec<U256>,
         required: U256,
     ) -> Result<(), Vec<u8>> {
         // Ensure not already initialized
@@ -79,13 +322,12 @@ impl MultisigWallet {
             return Err(b"Owners required".to_vec());
         }
 
-        let required_u256 = required;
-        if required_u256.is_zero() || required_u256 > U256::from(owners.len()) {
-            return Err(b"Invalid required confirmations".to_vec());
+        if owners.len() != weights.len() {
+            return Err(b"Owner and weight length mismatch".to_vec());
         }
 
         // Add owners
-        for owner in owners.iter() {
+        for (owner, weight) in owners.iter().zip(weights.iter()) {
             if *owner == Address::ZERO {
                 return Err(b"Invalid owner address".to_vec());
             }
@@ -94,10 +336,25 @@ impl MultisigWallet {
                 return Err(b"Duplicate owner".to_vec());
             }
 
+            if weight.is_zero() {
+                return Err(b"Invalid owner weight".to_vec());
+            }
+
             self.owners.push(*owner);
             self.is_owner.setter(*owner).set(true);
+            self.owner_weights.setter(*owner).set(*weight);
+            let total_weight = self.add_weight(self.total_weight.get(), *weight)?;
+            self.total_weight.set(total_weight);
 
             evm::log(OwnerAdded { owner: *owner });
+            evm::log(OwnerWeightChanged {
+                owner: *owner,
+                weight: *weight,
+            });
+        }
+
+        if required.is_zero() || required > self.total_weight.get() {
+            return Err(b"Invalid required confirmations".to_vec());
         }
 
         self.required_confirmations.set(required);
@@ -109,11 +366,17 @@ impl MultisigWallet {
     }
 
     /// Submit a new transaction
+    ///
+    /// `valid_until` is the last timestamp at which the transaction can be
+    /// executed; pass zero for no deadline. `gas_limit` is the gas forwarded
+    /// to the call; pass zero to forward all remaining gas.
     pub fn submit_transaction(
         &mut self,
         to: Address,
         value: U256,
         data: Vec<u8>,
+        valid_until: U256,
+        gas_limit: U256,
     ) -> Result<U256, Vec<u8>> {
         self.require_owner()?;
 
@@ -121,26 +384,87 @@ impl MultisigWallet {
             return Err(b"Invalid destination".to_vec());
         }
 
-        // Create new transaction
-        let tx_id = U256::from(self.transactions.len());
-        let mut new_tx = self.transactions.grow();
+        let tx_id = self._create_transaction(
+            TransactionKind::Call,
+            to,
+            value,
+            &data,
+            valid_until,
+            gas_limit,
+        )?;
 
-        new_tx.destination.set(to);
-        new_tx.value.set(value);
+        // Auto-confirm by submitter
+        self._confirm_transaction(tx_id)?;
 
-        // Store data
-        for byte in data.iter() {
-            new_tx.data.push(*byte);
+        Ok(tx_id)
+    }
+
+    /// Submit a batch of calls executed atomically as one transaction
+    ///
+    /// If any call fails the whole batch reverts and the error names the
+    /// index of the failing call. `gas_limit` applies to each call.
+    pub fn submit_batch_transaction(
+        &mut self,
+        targets: Vec<Address>,
+        values: Vec<U256>,
+        datas: Vec<Vec<u8>>,
+        valid_until: U256,
+        gas_limit: U256,
+    ) -> Result<U256, Vec<u8>> {
+        self.require_owner()?;
+
+        if targets.is_empty() {
+            return Err(b"Calls required".to_vec());
         }
 
-        new_tx.executed.set(false);
-        new_tx.confirmation_count.set(U256::ZERO);
+        if targets.len() != values.len() || targets.len() != datas.len() {
+            return Err(b"Call length mismatch".to_vec());
+        }
 
-        evm::log(TransactionSubmitted {
+        if targets.iter().any(|target| *target == Address::ZERO) {
+            return Err(b"Invalid destination".to_vec());
+        }
+
+        // Guard changes skip the guard, so they must not cover other calls
+        if targets
+            .iter()
+            .zip(datas.iter())
+            .any(|(target, data)| self.is_set_guard_call(*target, data))
+        {
+            return Err(b"set_guard cannot be batched".to_vec());
+        }
+
+        let mut total_value = U256::ZERO;
+        for value in values.iter() {
+            total_value = total_value
+                .checked_add(*value)
+                .ok_or_else(|| b"Value overflow".to_vec())?;
+        }
+        let tx_id = self._create_transaction(
+            TransactionKind::Batch,
+            Address::ZERO,
+            total_value,
+            &[],
+            valid_until,
+            gas_limit,
+        )?;
+
+        // Store calls
+        {
+            let tx = self.get_transaction_mut(tx_id)?;
+            for i in 0..targets.len() {
+                let mut batch_call = tx.calls.grow();
+                batch_call.destination.set(targets[i]);
+                batch_call.value.set(values[i]);
+                for byte in datas[i].iter() {
+                    batch_call.data.push(*byte);
+                }
+            }
+        }
+
+        evm::log(BatchSubmitted {
             txId: tx_id,
-            submitter: msg::sender(),
-            to,
-            value,
+            callCount: U256::from(targets.len()),
         });
 
         // Auto-confirm by submitter
@@ -149,6 +473,51 @@ impl MultisigWallet {
         Ok(tx_id)
     }
 
+    /// Submit a transaction that deploys a contract from the wallet
+    ///
+    /// `init_code` is the contract creation code including constructor
+    /// arguments. With `use_create2` the address is derived from `salt`,
+    /// otherwise from the wallet's account nonce and `salt` is ignored.
+    pub fn submit_deployment(
+        &mut self,
+        value: U256,
+        init_code: Vec<u8>,
+        use_create2: bool,
+        salt: B256,
+        valid_until: U256,
+    ) -> Result<U256, Vec<u8>> {
+        self.require_owner()?;
+
+        if init_code.is_empty() {
+            return Err(b"Init code required".to_vec());
+        }
+
+        let kind = if use_create2 {
+            TransactionKind::Create2
+        } else {
+            TransactionKind::Create
+        };
+
+        let tx_id = self._create_transaction(
+            kind,
+            Address::ZERO,
+            value,
+            &init_code,
+            valid_until,
+            U256::ZERO,
+        )?;
+
+        if use_create2 {
+            let tx = self.get_transaction_mut(tx_id)?;
+            tx.salt.set(salt);
+        }
+
+        // Auto-confirm by submitter
+        self._confirm_transaction(tx_id)?;
+
+        Ok(tx_id)
+    }
+
     /// Confirm a transaction
     pub fn confirm_transaction(&mut self, tx_id: U256) -> Result<(), Vec<u8>> {
         self.require_owner()?;
@@ -178,14 +547,24 @@ impl MultisigWallet {
             owner: sender,
         });
 
+        self.update_ready_at(tx_id)?;
+
         Ok(())
     }
 
     /// Execute a confirmed transaction
+    ///
+    /// On success the hash of the return data is stored and emitted; for a
+    /// batch it is the hash of the concatenated per-call return data hashes.
+    /// If a call reverts the whole transaction reverts and stays pending. The
+    /// call's revert data is bubbled up unchanged, or wrapped in
+    /// `BatchCallFailed(index, reason)` for batches. Deployment transactions
+    /// store the deployed address and hash its ABI encoding as the return data.
     pub fn execute_transaction(&mut self, tx_id: U256) -> Result<(), Vec<u8>> {
         self.require_owner()?;
-        self.require_not_executed(tx_id)?;
+        self.require_pending(tx_id)?;
         self.require_confirmed(tx_id)?;
+        self.require_delay_passed(tx_id)?;
 
         // Mark as executed before external call (reentrancy protection)
         {
@@ -194,68 +573,252 @@ impl MultisigWallet {
         }
 
         // Get transaction details (separate scope to avoid borrow issues)
-        let (destination, value, data) = {
+        let (kind, calls, gas_limit) = {
             let tx = self.get_transaction_ref(tx_id)?;
-            let destination = tx.destination.get();
-            let value = tx.value.get();
-
-            // Read data from storage
-            let mut data_vec = Vec::new();
-            for i in 0..tx.data.len() {
-                if let Some(byte) = tx.data.get(i) {
-                    data_vec.push(byte);
-                }
-            }
+            (tx.kind.get().to::<u8>(), self.transaction_calls(tx), tx.gas_limit.get().to::<u64>())
+        };
+
+        let executor = msg::sender();
+
+        // Every call is checked by the guard set when execution started
+        let guard = self.guard.get();
+
+        if kind == TransactionKind::Create as u8 || kind == TransactionKind::Create2 as u8 {
+            return self.execute_deployment(tx_id, guard, executor);
+        }
+
+        let mut return_data_hashes = Vec::with_capacity(calls.len() * 32);
+
+        // Execute external calls; any failure reverts the whole transaction
+        for (index, (destination, value, data)) in calls.iter().enumerate() {
+            self.check_guard_before(guard, *destination, *value, data, executor)?;
+
+            let context = if gas_limit != 0 {
+                self.require_gas_for_call(gas_limit)?;
+                Call::new_in(self).value(*value).gas(gas_limit)
+            } else {
+                Call::new_in(self).value(*value)
+            };
+
+            let call_result = unsafe { call(context, *destination, data) };
+
+            // Reverting also rolls back the executed flag set above
+            let return_data = call_result
+                .map_err(|error| self.execution_error(kind, index, error.into()))?;
+
+            self.check_guard_after(guard, *destination, *value, data, executor)?;
+
+            return_data_hashes.extend_from_slice(keccak(&return_data).as_slice());
+        }
 
-            (destination, value, data_vec)
+        let return_data_hash = if kind == TransactionKind::Batch as u8 {
+            keccak(&return_data_hashes)
+        } else {
+            B256::from_slice(&return_data_hashes)
         };
 
-        // Execute external call
+        {
+            let tx = self.get_transaction_mut(tx_id)?;
+            tx.return_data_hash.set(return_data_hash);
+        }
+
+        evm::log(TransactionExecuted {
+            txId: tx_id,
+            executor,
+            returnDataHash: return_data_hash,
+        });
+
+        Ok(())
+    }
+
+    /// Execute a transaction authorized by owner signatures collected off-chain
+    ///
+    /// `signatures` holds 65-byte ECDSA signatures (r || s || v) over
+    /// `get_transaction_hash(to, value, data, nonce)` from owners whose weights
+    /// add up to the weight the confirmation policy requires for the call,
+    /// sorted by signer address in ascending order. Anyone may submit them.
+    pub fn exec_transaction(
+        &mut self,
+        to: Address,
+        value: U256,
+        data: Vec<u8>,
+        nonce: U256,
+        signatures: Vec<u8>,
+    ) -> Result<(), Vec<u8>> {
+        if to == Address::ZERO {
+            return Err(b"Invalid destination".to_vec());
+        }
+
+        // Signed execution skips the confirmation window owners rely on to revoke
+        if !self.execution_delay.get().is_zero() {
+            return Err(b"Signed execution disabled while execution delay is set".to_vec());
+        }
+
+        if nonce != self.nonce.get() {
+            return Err(b"Invalid nonce".to_vec());
+        }
+
+        let tx_hash = self.transaction_hash(to, value, &data, nonce);
+        let required = self.call_required_weight(to, value, value, &data);
+        self.check_signatures(tx_hash, &signatures, required)?;
+
+        // Consume nonce before external call (replay and reentrancy protection)
+        self.nonce.set(nonce + U256::from(1));
+
+        let executor = msg::sender();
+        let guard = self.guard.get();
+        self.check_guard_before(guard, to, value, &data, executor)?;
+
         let call_result = unsafe {
             call(
                 Call::new_in(self)
                     .value(value),
-                destination,
+                to,
                 &data,
             )
         };
 
-        let executor = msg::sender();
+        let return_data = call_result
+            .map_err(|error| self.execution_error(TransactionKind::Call as u8, 0, error.into()))?;
+
+        self.check_guard_after(guard, to, value, &data, executor)?;
+
+        evm::log(SignedTransactionExecuted {
+            nonce,
+            txHash: tx_hash,
+            executor,
+            returnDataHash: keccak(&return_data),
+        });
+
+        Ok(())
+    }
+
+    /// Get the nonce the next signed transaction must use
+    pub fn get_nonce(&self) -> Result<U256, Vec<u8>> {
+        Ok(self.nonce.get())
+    }
+
+    /// Get the EIP-712 domain separator
+    pub fn domain_separator(&self) -> Result<B256, Vec<u8>> {
+        Ok(self.eip712_domain_separator())
+    }
+
+    /// Get the EIP-712 hash owners sign to authorize `exec_transaction`
+    pub fn get_transaction_hash(
+        &self,
+        to: Address,
+        value: U256,
+        data: Vec<u8>,
+        nonce: U256,
+    ) -> Result<B256, Vec<u8>> {
+        Ok(self.transaction_hash(to, value, &data, nonce))
+    }
+
+    /// Cancel a pending transaction
+    ///
+    /// The original submitter cancels immediately. Any other owner records a
+    /// cancellation vote, and the transaction is cancelled once current owners
+    /// holding `required_confirmations` weight have voted to cancel. Votes
+    /// from removed owners do not count.
+    pub fn cancel_transaction(&mut self, tx_id: U256) -> Result<(), Vec<u8>> {
+        self.require_owner()?;
+        self.require_not_executed(tx_id)?;
 
-        match call_result {
-            Ok(_) => {
-                evm::log(TransactionExecuted {
-                    txId: tx_id,
-                    executor,
-                });
-                Ok(())
+        let sender = msg::sender();
+
+        let tx = self.get_transaction_mut(tx_id)?;
+        if tx.cancelled.get() {
+            return Err(b"Transaction already cancelled".to_vec());
+        }
+
+        if sender != tx.submitter.get() {
+            if tx.cancellations.get(sender) {
+                return Err(b"Cancellation already confirmed".to_vec());
+            }
+
+            tx.cancellations.setter(sender).set(true);
+
+            evm::log(CancellationConfirmed {
+                txId: tx_id,
+                owner: sender,
+            });
+
+            let cancellation_weight = {
+                let tx = self.get_transaction_ref(tx_id)?;
+                self.cancellation_weight(tx)?
+            };
+
+            if cancellation_weight < self.required_confirmations.get() {
+                return Ok(());
             }
-            Err(_) => {
-                // Mark as not executed if call failed
-                let tx = self.get_transaction_mut(tx_id)?;
-                tx.executed.set(false);
+        }
+
+        let tx = self.get_transaction_mut(tx_id)?;
+        tx.cancelled.set(true);
+
+        evm::log(TransactionCancelled {
+            txId: tx_id,
+            canceller: sender,
+        });
 
-                evm::log(TransactionFailed {
-                    txId: tx_id,
-                    executor,
-                });
+        Ok(())
+    }
+
+    /// Get total weight of current owners who voted to cancel a transaction
+    pub fn get_cancellation_weight(&self, tx_id: U256) -> Result<U256, Vec<u8>> {
+        let tx = self.get_transaction_ref(tx_id)?;
+        self.cancellation_weight(tx)
+    }
+
+    /// Mark a transaction whose deadline has passed as expired
+    pub fn expire_transaction(&mut self, tx_id: U256) -> Result<(), Vec<u8>> {
+        self.require_owner()?;
+        self.require_not_executed(tx_id)?;
 
-                Err(b"Transaction execution failed".to_vec())
+        let valid_until = {
+            let tx = self.get_transaction_ref(tx_id)?;
+            if tx.cancelled.get() {
+                return Err(b"Transaction cancelled".to_vec());
+            }
+            if tx.expired.get() {
+                return Err(b"Transaction already expired".to_vec());
             }
+            tx.valid_until.get()
+        };
+
+        if valid_until.is_zero() || self.current_timestamp() <= valid_until {
+            return Err(b"Transaction not expired".to_vec());
         }
+
+        let tx = self.get_transaction_mut(tx_id)?;
+        tx.expired.set(true);
+
+        evm::log(TransactionExpired {
+            txId: tx_id,
+            validUntil: valid_until,
+        });
+
+        Ok(())
+    }
+
+    /// Get transaction status (0 = Pending, 1 = Executed, 2 = Expired, 3 = Cancelled)
+    pub fn get_transaction_status(&self, tx_id: U256) -> Result<u8, Vec<u8>> {
+        Ok(self.transaction_status(tx_id)? as u8)
     }
 
     /// Get transaction details
+    /// Returns (destination, value, data, executed, confirmation weight, valid until)
     pub fn get_transaction(
         &self,
         tx_id: U256,
-    ) -> Result<(Address, U256, Vec<u8>, bool, U256), Vec<u8>> {
+    ) -> Result<(Address, U256, Vec<u8>, bool, U256, U256), Vec<u8>> {
         let tx = self.get_transaction_ref(tx_id)?;
 
         let destination = tx.destination.get();
         let value = tx.value.get();
         let executed = tx.executed.get();
-        let confirmation_count = tx.confirmation_count.get();
+        let confirmation_count = self.confirmation_weight(tx)?;
+        let valid_until = tx.valid_until.get();
 
         // Read data from storage
         let mut data = Vec::new();
@@ -265,49 +828,166 @@ impl MultisigWallet {
             }
         }
 
-        Ok((destination, value, data, executed, confirmation_count))
+        Ok((destination, value, data, executed, confirmation_count, valid_until))
     }
 
-    /// Get total number of transactions
-    pub fn get_transaction_count(&self) -> Result<U256, Vec<u8>> {
-        Ok(U256::from(self.transactions.len()))
+    /// Get the gas forwarded to each call of a transaction (zero = all remaining gas)
+    pub fn get_gas_limit(&self, tx_id: U256) -> Result<U256, Vec<u8>> {
+        let tx = self.get_transaction_ref(tx_id)?;
+        Ok(tx.gas_limit.get())
     }
 
-    /// Get number of confirmations for a transaction
-    pub fn get_confirmation_count(&self, tx_id: U256) -> Result<U256, Vec<u8>> {
+    /// Get the hash of the data an executed transaction returned (zero if not executed)
+    pub fn get_return_data_hash(&self, tx_id: U256) -> Result<B256, Vec<u8>> {
         let tx = self.get_transaction_ref(tx_id)?;
-        Ok(tx.confirmation_count.get())
+        Ok(tx.return_data_hash.get())
     }
 
-    /// Check if transaction is confirmed by owner
-    pub fn is_confirmed_by(&self, tx_id: U256, owner: Address) -> Result<bool, Vec<u8>> {
+    /// Get the address a deployment transaction created (zero if not executed)
+    pub fn get_deployed_address(&self, tx_id: U256) -> Result<Address, Vec<u8>> {
         let tx = self.get_transaction_ref(tx_id)?;
-        Ok(tx.confirmations.get(owner))
+        Ok(tx.deployed.get())
     }
 
-    /// Get list of all owners
-    pub fn get_owners(&self) -> Result<Vec<Address>, Vec<u8>> {
-        let mut owners = Vec::new();
-        for i in 0..self.owners.len() {
-            if let Some(owner) = self.owners.get(i) {
-                owners.push(owner);
-            }
-        }
-        Ok(owners)
+    /// Get transaction kind (0 = Call, 1 = Batch, 2 = Create, 3 = Create2)
+    pub fn get_transaction_kind(&self, tx_id: U256) -> Result<u8, Vec<u8>> {
+        let tx = self.get_transaction_ref(tx_id)?;
+        Ok(tx.kind.get().to::<u8>())
     }
 
-    /// Check if address is an owner
-    pub fn is_owner(&self, address: Address) -> Result<bool, Vec<u8>> {
-        Ok(self.is_owner.get(address))
-    }
+    /// Get the calls a transaction makes (targets, values, datas)
+    pub fn get_transaction_calls(
+        &self,
+        tx_id: U256,
+    ) -> Result<(Vec<Address>, Vec<U256>, Vec<Vec<u8>>), Vec<u8>> {
+        let tx = self.get_transaction_ref(tx_id)?;
 
-    /// Get required confirmations
-    pub fn get_required_confirmations(&self) -> Result<U256, Vec<u8>> {
-        Ok(self.required_confirmations.get())
+        let mut targets = Vec::new();
+        let mut values = Vec::new();
+        let mut datas = Vec::new();
+        for (destination, value, data) in self.transaction_calls(tx) {
+            targets.push(destination);
+            values.push(value);
+            datas.push(data);
+        }
+
+        Ok((targets, values, datas))
     }
 
-    /// Add a new owner (requires multisig confirmation via transaction)
-    pub fn add_owner(&mut self, owner: Address) -> Result<(), Vec<u8>> {
+    /// Get total number of transactions
+    pub fn get_transaction_count(&self) -> Result<U256, Vec<u8>> {
+        Ok(U256::from(self.transactions.len()))
+    }
+
+    /// Get IDs of pending and/or executed transactions, oldest first
+    ///
+    /// `offset` and `limit` page through the matching transactions, not the
+    /// raw ID range. Expired and cancelled transactions are never included.
+    pub fn get_transaction_ids(
+        &self,
+        offset: U256,
+        limit: U256,
+        include_pending: bool,
+        include_executed: bool,
+    ) -> Result<Vec<U256>, Vec<u8>> {
+        let mut ids = Vec::new();
+        let mut skipped = U256::ZERO;
+
+        for i in 0..self.transactions.len() {
+            if U256::from(ids.len()) >= limit {
+                break;
+            }
+
+            let tx_id = U256::from(i);
+            let matches = match self.transaction_status(tx_id)? {
+                TransactionStatus::Pending => include_pending,
+                TransactionStatus::Executed => include_executed,
+                TransactionStatus::Expired | TransactionStatus::Cancelled => false,
+            };
+            if !matches {
+                continue;
+            }
+
+            if skipped < offset {
+                skipped += U256::from(1);
+                continue;
+            }
+
+            ids.push(tx_id);
+        }
+
+        Ok(ids)
+    }
+
+    /// Get the number of transactions in each status
+    ///
+    /// Returns (pending, executed, expired, cancelled).
+    pub fn get_transaction_count_by_status(&self) -> Result<(U256, U256, U256, U256), Vec<u8>> {
+        let mut counts = [U256::ZERO; 4];
+
+        for i in 0..self.transactions.len() {
+            let status = self.transaction_status(U256::from(i))?;
+            counts[status as usize] += U256::from(1);
+        }
+
+        Ok((counts[0], counts[1], counts[2], counts[3]))
+    }
+
+    /// Get total confirmation weight for a transaction
+    ///
+    /// Only confirmations from current owners are counted.
+    pub fn get_confirmation_count(&self, tx_id: U256) -> Result<U256, Vec<u8>> {
+        let tx = self.get_transaction_ref(tx_id)?;
+        self.confirmation_weight(tx)
+    }
+
+    /// Get the current owners who have confirmed a transaction
+    pub fn get_confirmations(&self, tx_id: U256) -> Result<Vec<Address>, Vec<u8>> {
+        let tx = self.get_transaction_ref(tx_id)?;
+        Ok(self.current_confirmations(tx))
+    }
+
+    /// Check if transaction is confirmed by owner
+    pub fn is_confirmed_by(&self, tx_id: U256, owner: Address) -> Result<bool, Vec<u8>> {
+        let tx = self.get_transaction_ref(tx_id)?;
+        Ok(tx.confirmations.get(owner))
+    }
+
+    /// Get list of all owners and their weights
+    pub fn get_owners(&self) -> Result<(Vec<Address>, Vec<U256>), Vec<u8>> {
+        let mut owners = Vec::new();
+        let mut weights = Vec::new();
+        for i in 0..self.owners.len() {
+            if let Some(owner) = self.owners.get(i) {
+                owners.push(owner);
+                weights.push(self.owner_weights.get(owner));
+            }
+        }
+        Ok((owners, weights))
+    }
+
+    /// Get an owner's confirmation weight
+    pub fn get_owner_weight(&self, owner: Address) -> Result<U256, Vec<u8>> {
+        Ok(self.owner_weights.get(owner))
+    }
+
+    /// Get total weight of all owners
+    pub fn get_total_weight(&self) -> Result<U256, Vec<u8>> {
+        Ok(self.total_weight.get())
+    }
+
+    /// Check if address is an owner
+    pub fn is_owner(&self, address: Address) -> Result<bool, Vec<u8>> {
+        Ok(self.is_owner.get(address))
+    }
+
+    /// Get required confirmation weight
+    pub fn get_required_confirmations(&self) -> Result<U256, Vec<u8>> {
+        Ok(self.required_confirmations.get())
+    }
+
+    /// Add a new owner (requires multisig confirmation via transaction)
+    pub fn add_owner(&mut self, owner: Address, weight: U256) -> Result<(), Vec<u8>> {
         self.require_wallet()?;
 
         if owner == Address::ZERO {
@@ -318,10 +998,19 @@ impl MultisigWallet {
             return Err(b"Owner already exists".to_vec());
         }
 
+        if weight.is_zero() {
+            return Err(b"Invalid owner weight".to_vec());
+        }
+
         self.owners.push(owner);
         self.is_owner.setter(owner).set(true);
+        self.owner_weights.setter(owner).set(weight);
+        let total_weight = self.add_weight(self.total_weight.get(), weight)?;
+        self.total_weight.set(total_weight);
+        self.bump_config_nonce();
 
         evm::log(OwnerAdded { owner });
+        evm::log(OwnerWeightChanged { owner, weight });
 
         Ok(())
     }
@@ -334,11 +1023,14 @@ impl MultisigWallet {
             return Err(b"Not an owner".to_vec());
         }
 
-        let owner_count = self.owners.len();
-        if U256::from(owner_count) <= self.required_confirmations.get() {
+        let weight = self.owner_weights.get(owner);
+        let remaining_weight = self.total_weight.get() - weight;
+        if remaining_weight < self.highest_threshold() {
             return Err(b"Cannot remove owner: would break requirement".to_vec());
         }
 
+        let owner_count = self.owners.len();
+
         // Find and remove owner
         for i in 0..owner_count {
             if let Some(current_owner) = self.owners.get(i) {
@@ -356,6 +1048,9 @@ impl MultisigWallet {
         }
 
         self.is_owner.setter(owner).set(false);
+        self.owner_weights.setter(owner).set(U256::ZERO);
+        self.total_weight.set(remaining_weight);
+        self.bump_config_nonce();
 
         evm::log(OwnerRemoved { owner });
 
@@ -363,6 +1058,8 @@ impl MultisigWallet {
     }
 
     /// Replace an owner (requires multisig confirmation via transaction)
+    ///
+    /// The new owner takes over the old owner's weight.
     pub fn replace_owner(
         &mut self,
         old_owner: Address,
@@ -392,136 +1089,1843 @@ impl MultisigWallet {
             }
         }
 
+        let weight = self.owner_weights.get(old_owner);
+
         self.is_owner.setter(old_owner).set(false);
         self.is_owner.setter(new_owner).set(true);
+        self.owner_weights.setter(old_owner).set(U256::ZERO);
+        self.owner_weights.setter(new_owner).set(weight);
+        self.bump_config_nonce();
 
         evm::log(OwnerRemoved { owner: old_owner });
         evm::log(OwnerAdded { owner: new_owner });
+        evm::log(OwnerWeightChanged {
+            owner: new_owner,
+            weight,
+        });
+
+        Ok(())
+    }
+
+    /// Change an owner's weight (requires multisig confirmation via transaction)
+    pub fn set_owner_weight(&mut self, owner: Address, weight: U256) -> Result<(), Vec<u8>> {
+        self.require_wallet()?;
+
+        if !self.is_owner.get(owner) {
+            return Err(b"Not an owner".to_vec());
+        }
+
+        if weight.is_zero() {
+            return Err(b"Invalid owner weight".to_vec());
+        }
+
+        let total_weight =
+            self.add_weight(self.total_weight.get() - self.owner_weights.get(owner), weight)?;
+        if total_weight < self.highest_threshold() {
+            return Err(b"Cannot change weight: would break requirement".to_vec());
+        }
+
+        self.owner_weights.setter(owner).set(weight);
+        self.total_weight.set(total_weight);
+        self.bump_config_nonce();
+
+        evm::log(OwnerWeightChanged { owner, weight });
 
         Ok(())
     }
 
-    /// Change the required confirmations (requires multisig confirmation via transaction)
+    /// Change the required confirmation weight (requires multisig confirmation via transaction)
     pub fn change_requirement(&mut self, required: U256) -> Result<(), Vec<u8>> {
         self.require_wallet()?;
 
-        let owner_count = U256::from(self.owners.len());
-        if required.is_zero() || required > owner_count {
+        if required.is_zero() || required > self.total_weight.get() {
             return Err(b"Invalid required confirmations".to_vec());
         }
 
         self.required_confirmations.set(required);
+        self.bump_config_nonce();
 
         evm::log(RequirementChanged { required });
 
         Ok(())
     }
 
-    /// Receive ETH deposits
-    #[payable]
-    pub fn deposit(&self) -> Result<(), Vec<u8>> {
-        evm::log(Deposit {
-            sender: msg::sender(),
-            value: msg::value(),
+    /// Set the confirmation policy (requires multisig confirmation via transaction)
+    ///
+    /// Plain ETH transfers of at most `transfer_cap` need `transfer_threshold`
+    /// weight, calls to other contracts and larger transfers need
+    /// `call_threshold`, and self-calls that change owners, guardians,
+    /// modules, the guard or this policy need `owner_management_threshold`.
+    /// Other self-calls need `required_confirmations`, as does any tier whose
+    /// threshold is zero.
+    pub fn set_confirmation_policy(
+        &mut self,
+        transfer_cap: U256,
+        transfer_threshold: U256,
+        call_threshold: U256,
+        owner_management_threshold: U256,
+    ) -> Result<(), Vec<u8>> {
+        self.require_wallet()?;
+
+        let total_weight = self.total_weight.get();
+        if transfer_threshold > total_weight
+            || call_threshold > total_weight
+            || owner_management_threshold > total_weight
+        {
+            return Err(b"Invalid policy threshold".to_vec());
+        }
+
+        self.transfer_cap.set(transfer_cap);
+        self.transfer_threshold.set(transfer_threshold);
+        self.call_threshold.set(call_threshold);
+        self.owner_management_threshold.set(owner_management_threshold);
+        self.bump_config_nonce();
+
+        evm::log(ConfirmationPolicyChanged {
+            transferCap: transfer_cap,
+            transferThreshold: transfer_threshold,
+            callThreshold: call_threshold,
+            ownerManagementThreshold: owner_management_threshold,
         });
+
         Ok(())
     }
 
-    /// Get wallet balance
-    pub fn get_balance(&self) -> Result<U256, Vec<u8>> {
-        Ok(self.balance())
+    /// Get the confirmation policy
+    ///
+    /// Returns (transfer cap, transfer threshold, call threshold, owner management threshold).
+    pub fn get_confirmation_policy(&self) -> Result<(U256, U256, U256, U256), Vec<u8>> {
+        Ok((
+            self.transfer_cap.get(),
+            self.transfer_threshold.get(),
+            self.call_threshold.get(),
+            self.owner_management_threshold.get(),
+        ))
     }
-}
 
-/// Internal helper methods
-impl MultisigWallet {
-    /// Require that caller is an owner
-    fn require_owner(&self) -> Result<(), Vec<u8>> {
-        if !self.is_owner.get(msg::sender()) {
-            return Err(b"Not an owner".to_vec());
-        }
+    /// Get the confirmation weight a transaction needs under the current policy
+    pub fn get_required_weight(&self, tx_id: U256) -> Result<U256, Vec<u8>> {
+        let tx = self.get_transaction_ref(tx_id)?;
+        Ok(self.required_weight(tx))
+    }
+
+    /// Set the daily spending limit for a token (requires multisig confirmation via transaction)
+    ///
+    /// Use the zero address for native ETH. A zero amount removes the limit.
+    pub fn set_spending_limit(&mut self, token: Address, amount: U256) -> Result<(), Vec<u8>> {
+        self.require_wallet()?;
+
+        let mut limit = self.spending_limits.setter(token);
+        limit.amount.set(amount);
+
+        evm::log(SpendingLimitChanged { token, amount });
+
         Ok(())
     }
 
-    /// Require that caller is the wallet itself (for owner management)
-    fn require_wallet(&self) -> Result<(), Vec<u8>> {
-        if msg::sender() != self.address() {
-            return Err(b"Only wallet can call this".to_vec());
+    /// Spend up to the daily limit without confirmations (any single owner)
+    ///
+    /// The limit applies to any rolling 24 hour period: a spend counts
+    /// against the limit until 24 hours after it was made.
+    pub fn spend_within_limit(
+        &mut self,
+        token: Address,
+        to: Address,
+        amount: U256,
+    ) -> Result<(), Vec<u8>> {
+        self.require_owner()?;
+
+        if to == Address::ZERO {
+            return Err(b"Invalid recipient".to_vec());
+        }
+
+        if amount.is_zero() {
+            return Err(b"Amount must be positive".to_vec());
+        }
+
+        let now = self.current_timestamp();
+
+        // Record spend before external call (reentrancy protection)
+        {
+            let mut limit = self.spending_limits.setter(token);
+
+            // Skip spends that have left the window
+            let mut first_active = limit.first_active.get().to::<usize>();
+            while let Some(spend_time) = limit.spend_times.get(first_active) {
+                if spend_time + U256::from(SPENDING_LIMIT_WINDOW) > now {
+                    break;
+                }
+                first_active += 1;
+            }
+            limit.first_active.set(U256::from(first_active));
+
+            let mut spent = amount;
+            for i in first_active..limit.spend_amounts.len() {
+                if let Some(spend_amount) = limit.spend_amounts.get(i) {
+                    spent += spend_amount;
+                }
+            }
+
+            if spent > limit.amount.get() {
+                return Err(b"Spending limit exceeded".to_vec());
+            }
+
+            limit.spend_times.push(now);
+            limit.spend_amounts.push(amount);
         }
+
+        self.send_funds(token, to, amount)?;
+
+        evm::log(SpendingLimitUsed {
+            token,
+            to,
+            owner: msg::sender(),
+            amount,
+        });
+
         Ok(())
     }
 
-    /// Require that transaction exists and is not executed
-    fn require_not_executed(&self, tx_id: U256) -> Result<(), Vec<u8>> {
-        let tx = self.get_transaction_ref(tx_id)?;
-        if tx.executed.get() {
-            return Err(b"Transaction already executed".to_vec());
+    /// Get the spending limit for a token
+    ///
+    /// Returns (amount, spent in the last 24 hours, time the oldest of those
+    /// spends leaves the window), with a zero time if nothing was spent.
+    pub fn get_spending_limit(&self, token: Address) -> Result<(U256, U256, U256), Vec<u8>> {
+        let limit = self.spending_limits.get(token);
+        let now = self.current_timestamp();
+
+        let mut spent = U256::ZERO;
+        let mut next_release = U256::ZERO;
+        for i in limit.first_active.get().to::<usize>()..limit.spend_times.len() {
+            if let (Some(spend_time), Some(spend_amount)) =
+                (limit.spend_times.get(i), limit.spend_amounts.get(i))
+            {
+                let release = spend_time + U256::from(SPENDING_LIMIT_WINDOW);
+                if release > now {
+                    if next_release.is_zero() {
+                        next_release = release;
+                    }
+                    spent += spend_amount;
+                }
+            }
         }
-        Ok(())
+
+        Ok((limit.amount.get(), spent, next_release))
     }
 
-    /// Require that transaction is confirmed
-    fn require_confirmed(&self, tx_id: U256) -> Result<(), Vec<u8>> {
-        let tx = self.get_transaction_ref(tx_id)?;
-        let confirmation_count = tx.confirmation_count.get();
-        let required = self.required_confirmations.get();
+    /// Create a recurring payment schedule (requires multisig confirmation via transaction)
+    ///
+    /// Installment `n` (counting from zero) falls due at `start + n * interval`.
+    /// Use the zero token address for native ETH. Pass zero `start` to start
+    /// now and zero `end` for no end date. `start` cannot be in the past, so
+    /// a schedule never begins with installments already due.
+    pub fn create_payment_schedule(
+        &mut self,
+        recipient: Address,
+        token: Address,
+        amount: U256,
+        interval: U256,
+        start: U256,
+        end: U256,
+    ) -> Result<U256, Vec<u8>> {
+        self.require_wallet()?;
 
-        if confirmation_count < required {
-            return Err(b"Transaction not confirmed".to_vec());
+        if recipient == Address::ZERO {
+            return Err(b"Invalid recipient".to_vec());
+        }
+
+        if amount.is_zero() {
+            return Err(b"Amount must be positive".to_vec());
         }
+
+        if interval.is_zero() {
+            return Err(b"Invalid interval".to_vec());
+        }
+
+        let now = self.current_timestamp();
+        let start = if start.is_zero() { now } else { start };
+
+        if start < now {
+            return Err(b"Start in the past".to_vec());
+        }
+
+        if !end.is_zero() && end < start {
+            return Err(b"End before start".to_vec());
+        }
+
+        let schedule_id = U256::from(self.payment_schedules.len());
+        let mut schedule = self.payment_schedules.grow();
+
+        schedule.recipient.set(recipient);
+        schedule.token.set(token);
+        schedule.amount.set(amount);
+        schedule.interval.set(interval);
+        schedule.start.set(start);
+        schedule.end.set(end);
+        schedule.paid_periods.set(U256::ZERO);
+        schedule.cancelled.set(false);
+
+        evm::log(PaymentScheduleCreated {
+            scheduleId: schedule_id,
+            recipient,
+            token,
+            amount,
+            interval,
+            start,
+            end,
+        });
+
+        Ok(schedule_id)
+    }
+
+    /// Cancel a recurring payment schedule (requires multisig confirmation via transaction)
+    pub fn cancel_payment_schedule(&mut self, schedule_id: U256) -> Result<(), Vec<u8>> {
+        self.require_wallet()?;
+
+        let schedule = self.get_payment_schedule_mut(schedule_id)?;
+        if schedule.cancelled.get() {
+            return Err(b"Schedule already cancelled".to_vec());
+        }
+
+        schedule.cancelled.set(true);
+
+        evm::log(PaymentScheduleCancelled {
+            scheduleId: schedule_id,
+        });
+
         Ok(())
     }
 
-    /// Internal method to confirm a transaction
-    fn _confirm_transaction(&mut self, tx_id: U256) -> Result<(), Vec<u8>> {
-        self.require_not_executed(tx_id)?;
+    /// Pay the next due installment of a schedule
+    ///
+    /// Anyone can trigger a payment. One installment is paid per call, so a
+    /// schedule that fell behind is caught up with repeated calls.
+    pub fn execute_scheduled_payment(&mut self, schedule_id: U256) -> Result<(), Vec<u8>> {
+        let now = self.current_timestamp();
+
+        // Record the period before external call (reentrancy protection)
+        let (recipient, token, amount, period) = {
+            let schedule = self.get_payment_schedule_mut(schedule_id)?;
+            if schedule.cancelled.get() {
+                return Err(b"Schedule cancelled".to_vec());
+            }
 
-        let sender = msg::sender();
+            let period = schedule.paid_periods.get();
+            let due_at = schedule.start.get() + period * schedule.interval.get();
 
-        // Check if already confirmed
-        let tx = self.get_transaction_mut(tx_id)?;
-        if tx.confirmations.get(sender) {
-            return Err(b"Transaction already confirmed".to_vec());
-        }
+            let end = schedule.end.get();
+            if !end.is_zero() && due_at > end {
+                return Err(b"Schedule finished".to_vec());
+            }
 
-        // Add confirmation
-        tx.confirmations.setter(sender).set(true);
-        let current_count = tx.confirmation_count.get();
-        tx.confirmation_count.set(current_count + U256::from(1));
+            if now < due_at {
+                return Err(b"Payment not due".to_vec());
+            }
 
-        evm::log(TransactionConfirmed {
-            txId: tx_id,
-            owner: sender,
+            schedule.paid_periods.set(period + U256::from(1));
+
+            (
+                schedule.recipient.get(),
+                schedule.token.get(),
+                schedule.amount.get(),
+                period,
+            )
+        };
+
+        self.send_funds(token, recipient, amount)?;
+
+        evm::log(ScheduledPaymentExecuted {
+            scheduleId: schedule_id,
+            period,
+            recipient,
+            token,
+            amount,
         });
 
         Ok(())
     }
 
-    /// Get transaction reference (immutable)
-    fn get_transaction_ref(&self, tx_id: U256) -> Result<&Transaction, Vec<u8>> {
-        let index = tx_id.to::<usize>();
-        if index >= self.transactions.len() {
-            return Err(b"Transaction does not exist".to_vec());
+    /// Get payment schedule details
+    ///
+    /// Returns (recipient, token, amount, interval, start, end, paid periods, cancelled).
+    pub fn get_payment_schedule(
+        &self,
+        schedule_id: U256,
+    ) -> Result<(Address, Address, U256, U256, U256, U256, U256, bool), Vec<u8>> {
+        let schedule = self.get_payment_schedule_ref(schedule_id)?;
+        Ok((
+            schedule.recipient.get(),
+            schedule.token.get(),
+            schedule.amount.get(),
+            schedule.interval.get(),
+            schedule.start.get(),
+            schedule.end.get(),
+            schedule.paid_periods.get(),
+            schedule.cancelled.get(),
+        ))
+    }
+
+    /// Get the timestamp the next installment of a schedule falls due
+    pub fn get_next_payment_due(&self, schedule_id: U256) -> Result<U256, Vec<u8>> {
+        let schedule = self.get_payment_schedule_ref(schedule_id)?;
+        Ok(schedule.start.get() + schedule.paid_periods.get() * schedule.interval.get())
+    }
+
+    /// Get total number of payment schedules
+    pub fn get_payment_schedule_count(&self) -> Result<U256, Vec<u8>> {
+        Ok(U256::from(self.payment_schedules.len()))
+    }
+
+    /// Set the delay between final confirmation and execution (requires multisig confirmation via transaction)
+    ///
+    /// While a delay is set, `exec_transaction` is disabled so every
+    /// transaction goes through the on-chain confirmation window.
+    pub fn set_execution_delay(&mut self, delay: U256) -> Result<(), Vec<u8>> {
+        self.require_wallet()?;
+
+        self.execution_delay.set(delay);
+        self.bump_config_nonce();
+
+        evm::log(ExecutionDelayChanged { delay });
+
+        Ok(())
+    }
+
+    /// Get execution delay (in seconds)
+    pub fn get_execution_delay(&self) -> Result<U256, Vec<u8>> {
+        Ok(self.execution_delay.get())
+    }
+
+    /// Get the timestamp a transaction becomes executable
+    ///
+    /// Zero until fully confirmed, or if the delay was started before a change
+    /// to owners, weights, thresholds or the delay itself.
+    pub fn get_ready_at(&self, tx_id: U256) -> Result<U256, Vec<u8>> {
+        let tx = self.get_transaction_ref(tx_id)?;
+        if !self.delay_started(tx) {
+            return Ok(U256::ZERO);
         }
-        Ok(self.transactions.getter(index).unwrap())
+        Ok(tx.ready_at.get())
     }
 
-    /// Get transaction reference (mutable)
-    fn get_transaction_mut(&mut self, tx_id: U256) -> Result<&mut Transaction, Vec<u8>> {
-        let index = tx_id.to::<usize>();
-        if index >= self.transactions.len() {
-            return Err(b"Transaction does not exist".to_vec());
+    /// Start the execution delay for a fully confirmed transaction
+    ///
+    /// Needed when a transaction became confirmed without a confirmation,
+    /// e.g. after a threshold was lowered, or when a configuration change
+    /// invalidated a delay that had already started.
+    pub fn start_execution_delay(&mut self, tx_id: U256) -> Result<(), Vec<u8>> {
+        self.require_owner()?;
+        self.require_pending(tx_id)?;
+        self.require_confirmed(tx_id)?;
+
+        if self.delay_started(self.get_transaction_ref(tx_id)?) {
+            return Err(b"Execution delay already started".to_vec());
         }
-        Ok(self.transactions.setter(index).unwrap())
+
+        self.update_ready_at(tx_id)
     }
 
-    /// Get contract balance
-    fn balance(&self) -> U256 {
-        U256::from(self.address().balance())
+    /// Enable a module (requires multisig confirmation via transaction)
+    ///
+    /// Enabled modules can execute any call from the wallet, including
+    /// owner management self-calls, so only enable audited contracts.
+    pub fn enable_module(&mut self, module: Address) -> Result<(), Vec<u8>> {
+        self.require_wallet()?;
+
+        if module == Address::ZERO || module == self.address() {
+            return Err(b"Invalid module address".to_vec());
+        }
+
+        if self.is_module.get(module) {
+            return Err(b"Module already enabled".to_vec());
+        }
+
+        self.modules.push(module);
+        self.is_module.setter(module).set(true);
+
+        evm::log(EnabledModule { module });
+
+        Ok(())
     }
 
-    /// Get contract address
-    fn address(&self) -> Address {
-        Address::from(evm::contract_address().0)
+    /// Disable a module (requires multisig confirmation via transaction)
+    pub fn disable_module(&mut self, module: Address) -> Result<(), Vec<u8>> {
+        self.require_wallet()?;
+
+        if !self.is_module.get(module) {
+            return Err(b"Module not enabled".to_vec());
+        }
+
+        // Find and remove module
+        let module_count = self.modules.len();
+        for i in 0..module_count {
+            if let Some(current_module) = self.modules.get(i) {
+                if current_module == module {
+                    // Swap with last element and pop
+                    if i < module_count - 1 {
+                        if let Some(last_module) = self.modules.get(module_count - 1) {
+                            self.modules.setter(i).unwrap().set(last_module);
+                        }
+                    }
+                    self.modules.pop();
+                    break;
+                }
+            }
+        }
+
+        self.is_module.setter(module).set(false);
+
+        evm::log(DisabledModule { module });
+
+        Ok(())
+    }
+
+    /// Execute a call from the wallet without confirmations (enabled modules only)
+    pub fn exec_from_module(
+        &mut self,
+        to: Address,
+        value: U256,
+        data: Vec<u8>,
+    ) -> Result<Vec<u8>, Vec<u8>> {
+        let module = msg::sender();
+        if !self.is_module.get(module) {
+            return Err(b"Not an enabled module".to_vec());
+        }
+
+        if to == Address::ZERO {
+            return Err(b"Invalid destination".to_vec());
+        }
+
+        let call_result = unsafe {
+            call(
+                Call::new_in(self)
+                    .value(value),
+                to,
+                &data,
+            )
+        };
+
+        let return_data = call_result.map_err(|_| b"Module transaction failed".to_vec())?;
+
+        evm::log(ExecutionFromModule { module, to, value });
+
+        Ok(return_data)
+    }
+
+    /// Set the transaction guard (requires multisig confirmation via transaction)
+    ///
+    /// The guard is called before and after every call made by
+    /// `execute_transaction` and `exec_transaction`. Module executions are
+    /// not guarded, and neither are self-calls to `set_guard`, so a faulty
+    /// guard can always be replaced. Such calls cannot be batched with other
+    /// calls. Pass the zero address to remove the guard.
+    pub fn set_guard(&mut self, guard: Address) -> Result<(), Vec<u8>> {
+        self.require_wallet()?;
+
+        if guard == self.address() {
+            return Err(b"Invalid guard address".to_vec());
+        }
+
+        self.guard.set(guard);
+
+        evm::log(ChangedGuard { guard });
+
+        Ok(())
+    }
+
+    /// Get the transaction guard (zero if none)
+    pub fn get_guard(&self) -> Result<Address, Vec<u8>> {
+        Ok(self.guard.get())
+    }
+
+    /// Approve a message hash for ERC-1271 validation (requires multisig confirmation via transaction)
+    ///
+    /// Once approved, `is_valid_signature(hash, "")` returns the magic value.
+    pub fn sign_message(&mut self, hash: B256) -> Result<(), Vec<u8>> {
+        self.require_wallet()?;
+
+        self.signed_messages.insert(hash, true);
+
+        evm::log(SignMessage { hash });
+
+        Ok(())
+    }
+
+    /// Check whether a message hash was approved on-chain
+    pub fn is_message_signed(&self, hash: B256) -> Result<bool, Vec<u8>> {
+        Ok(self.signed_messages.get(hash))
+    }
+
+    /// Get the EIP-712 hash owners sign to approve `hash` off-chain
+    pub fn get_message_hash(&self, hash: B256) -> Result<B256, Vec<u8>> {
+        Ok(self.message_hash(hash))
+    }
+
+    /// ERC-1271 signature validation
+    ///
+    /// An empty `signature` is valid if `hash` was approved with
+    /// `sign_message`. Otherwise `signature` must hold owner signatures over
+    /// `get_message_hash(hash)`, in the same format as `exec_transaction`.
+    #[selector(name = "isValidSignature")]
+    pub fn is_valid_signature(&self, hash: B256, signature: Bytes) -> Result<FixedBytes<4>, Vec<u8>> {
+        let valid = if signature.is_empty() {
+            self.signed_messages.get(hash)
+        } else {
+            let required = self.required_confirmations.get();
+            self.check_signatures(self.message_hash(hash), &signature, required).is_ok()
+        };
+
+        if valid {
+            Ok(FixedBytes(ERC1271_MAGIC_VALUE))
+        } else {
+            Ok(FixedBytes(ERC1271_INVALID))
+        }
+    }
+
+    /// Add a recovery guardian (requires multisig confirmation via transaction)
+    pub fn add_guardian(&mut self, guardian: Address) -> Result<(), Vec<u8>> {
+        self.require_wallet()?;
+
+        if guardian == Address::ZERO || guardian == self.address() {
+            return Err(b"Invalid guardian address".to_vec());
+        }
+
+        if self.is_guardian.get(guardian) {
+            return Err(b"Guardian already exists".to_vec());
+        }
+
+        self.guardians.push(guardian);
+        self.is_guardian.setter(guardian).set(true);
+
+        evm::log(GuardianAdded { guardian });
+
+        Ok(())
+    }
+
+    /// Remove a recovery guardian (requires multisig confirmation via transaction)
+    pub fn remove_guardian(&mut self, guardian: Address) -> Result<(), Vec<u8>> {
+        self.require_wallet()?;
+
+        if !self.is_guardian.get(guardian) {
+            return Err(b"Not a guardian".to_vec());
+        }
+
+        let guardian_count = self.guardians.len();
+        if U256::from(guardian_count - 1) < self.guardian_threshold.get() {
+            return Err(b"Cannot remove guardian: would break threshold".to_vec());
+        }
+
+        // Find and remove guardian
+        for i in 0..guardian_count {
+            if let Some(current_guardian) = self.guardians.get(i) {
+                if current_guardian == guardian {
+                    // Swap with last element and pop
+                    if i < guardian_count - 1 {
+                        if let Some(last_guardian) = self.guardians.get(guardian_count - 1) {
+                            self.guardians.setter(i).unwrap().set(last_guardian);
+                        }
+                    }
+                    self.guardians.pop();
+                    break;
+                }
+            }
+        }
+
+        self.is_guardian.setter(guardian).set(false);
+
+        evm::log(GuardianRemoved { guardian });
+
+        Ok(())
+    }
+
+    /// Change the number of guardian approvals a recovery needs (requires multisig confirmation via transaction)
+    ///
+    /// A zero threshold disables recovery. Enabling recovery requires a
+    /// recovery delay of at least `MIN_RECOVERY_DELAY` to be set first.
+    pub fn change_guardian_threshold(&mut self, threshold: U256) -> Result<(), Vec<u8>> {
+        self.require_wallet()?;
+
+        if threshold > U256::from(self.guardians.len()) {
+            return Err(b"Invalid guardian threshold".to_vec());
+        }
+
+        if !threshold.is_zero() {
+            self.require_min_recovery_delay()?;
+        }
+
+        self.guardian_threshold.set(threshold);
+
+        evm::log(GuardianThresholdChanged { threshold });
+
+        Ok(())
+    }
+
+    /// Set the delay between guardian approval and recovery (requires multisig confirmation via transaction)
+    ///
+    /// The delay must be at least `MIN_RECOVERY_DELAY`.
+    pub fn set_recovery_delay(&mut self, delay: U256) -> Result<(), Vec<u8>> {
+        self.require_wallet()?;
+
+        if delay < U256::from(MIN_RECOVERY_DELAY) {
+            return Err(b"Recovery delay too short".to_vec());
+        }
+
+        self.recovery_delay.set(delay);
+
+        evm::log(RecoveryDelayChanged { delay });
+
+        Ok(())
+    }
+
+    /// Propose a new owner set and requirement to recover the wallet
+    ///
+    /// The proposing guardian's approval is counted automatically.
+    pub fn propose_recovery(
+        &mut self,
+        owners: Vec<Address>,
+        weights: Vec<U256>,
+        required: U256,
+    ) -> Result<U256, Vec<u8>> {
+        self.require_guardian()?;
+
+        if self.guardian_threshold.get().is_zero() {
+            return Err(b"Recovery disabled".to_vec());
+        }
+        self.require_min_recovery_delay()?;
+
+        self.validate_owner_set(&owners, &weights, required)?;
+
+        let recovery_id = U256::from(self.recoveries.len());
+        let recovery_nonce = self.recovery_nonce.get();
+        let mut recovery = self.recoveries.grow();
+
+        for (owner, weight) in owners.iter().zip(weights.iter()) {
+            recovery.owners.push(*owner);
+            recovery.weights.push(*weight);
+        }
+        recovery.required.set(required);
+        recovery.recovery_nonce.set(recovery_nonce);
+
+        evm::log(RecoveryProposed {
+            recoveryId: recovery_id,
+            guardian: msg::sender(),
+        });
+
+        self._confirm_recovery(recovery_id)?;
+
+        Ok(recovery_id)
+    }
+
+    /// Approve a pending recovery
+    pub fn confirm_recovery(&mut self, recovery_id: U256) -> Result<(), Vec<u8>> {
+        self.require_guardian()?;
+        self.require_recovery_pending(recovery_id)?;
+
+        self._confirm_recovery(recovery_id)
+    }
+
+    /// Veto a pending recovery
+    ///
+    /// The recovery is vetoed once current owners holding
+    /// `required_confirmations` weight have voted against it. Votes from
+    /// removed owners do not count.
+    pub fn veto_recovery(&mut self, recovery_id: U256) -> Result<(), Vec<u8>> {
+        self.require_owner()?;
+        self.require_recovery_pending(recovery_id)?;
+
+        let sender = msg::sender();
+
+        {
+            let recovery = self.get_recovery_mut(recovery_id)?;
+            if recovery.vetoes.get(sender) {
+                return Err(b"Veto already confirmed".to_vec());
+            }
+            recovery.vetoes.setter(sender).set(true);
+        }
+
+        evm::log(RecoveryVetoConfirmed {
+            recoveryId: recovery_id,
+            owner: sender,
+        });
+
+        let veto_weight = {
+            let recovery = self.get_recovery_ref(recovery_id)?;
+            self.recovery_veto_weight(recovery)?
+        };
+
+        if veto_weight >= self.required_confirmations.get() {
+            let recovery = self.get_recovery_mut(recovery_id)?;
+            recovery.vetoed.set(true);
+
+            evm::log(RecoveryVetoed {
+                recoveryId: recovery_id,
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Replace the owner set with an approved recovery once its delay has passed
+    ///
+    /// Anyone can execute. Executing a recovery invalidates all other pending
+    /// recoveries.
+    pub fn execute_recovery(&mut self, recovery_id: U256) -> Result<(), Vec<u8>> {
+        self.require_recovery_pending(recovery_id)?;
+
+        let (owners, weights, required, ready_at, approvals) = {
+            let recovery = self.get_recovery_ref(recovery_id)?;
+            let mut owners = Vec::new();
+            let mut weights = Vec::new();
+            for i in 0..recovery.owners.len() {
+                if let (Some(owner), Some(weight)) = (recovery.owners.get(i), recovery.weights.get(i)) {
+                    owners.push(owner);
+                    weights.push(weight);
+                }
+            }
+            (
+                owners,
+                weights,
+                recovery.required.get(),
+                recovery.ready_at.get(),
+                self.recovery_approvals(recovery),
+            )
+        };
+
+        // Approvals are recounted against the current guardian set and threshold
+        let threshold = self.guardian_threshold.get();
+        if threshold.is_zero() || approvals < threshold || ready_at.is_zero() {
+            return Err(b"Recovery not approved".to_vec());
+        }
+
+        if self.current_timestamp() < ready_at {
+            return Err(b"Recovery delay not passed".to_vec());
+        }
+
+        {
+            let recovery = self.get_recovery_mut(recovery_id)?;
+            recovery.executed.set(true);
+        }
+        self.recovery_nonce.set(self.recovery_nonce.get() + U256::from(1));
+
+        // Remove current owners
+        let (current_owners, _) = self.get_owners()?;
+        for owner in current_owners {
+            self.owners.pop();
+            self.is_owner.setter(owner).set(false);
+            self.owner_weights.setter(owner).set(U256::ZERO);
+
+            evm::log(OwnerRemoved { owner });
+        }
+
+        // Install the recovered owner set
+        let mut total_weight = U256::ZERO;
+        for (owner, weight) in owners.iter().zip(weights.iter()) {
+            self.owners.push(*owner);
+            self.is_owner.setter(*owner).set(true);
+            self.owner_weights.setter(*owner).set(*weight);
+            total_weight = self.add_weight(total_weight, *weight)?;
+
+            evm::log(OwnerAdded { owner: *owner });
+            evm::log(OwnerWeightChanged {
+                owner: *owner,
+                weight: *weight,
+            });
+        }
+
+        self.total_weight.set(total_weight);
+        self.required_confirmations.set(required);
+
+        // Policy thresholds were sized for the old owner set
+        self.transfer_cap.set(U256::ZERO);
+        self.transfer_threshold.set(U256::ZERO);
+        self.call_threshold.set(U256::ZERO);
+        self.owner_management_threshold.set(U256::ZERO);
+        self.bump_config_nonce();
+
+        evm::log(RequirementChanged { required });
+        evm::log(ConfirmationPolicyChanged {
+            transferCap: U256::ZERO,
+            transferThreshold: U256::ZERO,
+            callThreshold: U256::ZERO,
+            ownerManagementThreshold: U256::ZERO,
+        });
+        evm::log(RecoveryExecuted {
+            recoveryId: recovery_id,
+            executor: msg::sender(),
+        });
+
+        Ok(())
+    }
+
+    /// Get recovery details
+    ///
+    /// Returns (owners, weights, required, approvals, ready_at, vetoed, executed).
+    pub fn get_recovery(
+        &self,
+        recovery_id: U256,
+    ) -> Result<(Vec<Address>, Vec<U256>, U256, U256, U256, bool, bool), Vec<u8>> {
+        let recovery = self.get_recovery_ref(recovery_id)?;
+
+        let mut owners = Vec::new();
+        let mut weights = Vec::new();
+        for i in 0..recovery.owners.len() {
+            if let (Some(owner), Some(weight)) = (recovery.owners.get(i), recovery.weights.get(i)) {
+                owners.push(owner);
+                weights.push(weight);
+            }
+        }
+
+        Ok((
+            owners,
+            weights,
+            recovery.required.get(),
+            self.recovery_approvals(recovery),
+            recovery.ready_at.get(),
+            recovery.vetoed.get(),
+            recovery.executed.get(),
+        ))
+    }
+
+    /// Get total number of recoveries proposed
+    pub fn get_recovery_count(&self) -> Result<U256, Vec<u8>> {
+        Ok(U256::from(self.recoveries.len()))
+    }
+
+    /// Get list of guardians
+    pub fn get_guardians(&self) -> Result<Vec<Address>, Vec<u8>> {
+        let mut guardians = Vec::new();
+        for i in 0..self.guardians.len() {
+            if let Some(guardian) = self.guardians.get(i) {
+                guardians.push(guardian);
+            }
+        }
+        Ok(guardians)
+    }
+
+    /// Check if address is a guardian
+    pub fn is_guardian(&self, address: Address) -> Result<bool, Vec<u8>> {
+        Ok(self.is_guardian.get(address))
+    }
+
+    /// Get the number of guardian approvals a recovery needs
+    pub fn get_guardian_threshold(&self) -> Result<U256, Vec<u8>> {
+        Ok(self.guardian_threshold.get())
+    }
+
+    /// Get the delay between guardian approval and recovery
+    pub fn get_recovery_delay(&self) -> Result<U256, Vec<u8>> {
+        Ok(self.recovery_delay.get())
+    }
+
+    /// Get list of enabled modules
+    pub fn get_modules(&self) -> Result<Vec<Address>, Vec<u8>> {
+        let mut modules = Vec::new();
+        for i in 0..self.modules.len() {
+            if let Some(module) = self.modules.get(i) {
+                modules.push(module);
+            }
+        }
+        Ok(modules)
+    }
+
+    /// Check if a module is enabled
+    pub fn is_module_enabled(&self, module: Address) -> Result<bool, Vec<u8>> {
+        Ok(self.is_module.get(module))
+    }
+
+    /// Receive ETH deposits
+    #[payable]
+    pub fn deposit(&self) -> Result<(), Vec<u8>> {
+        evm::log(Deposit {
+            sender: msg::sender(),
+            value: msg::value(),
+        });
+        Ok(())
+    }
+
+    /// Receive plain ETH transfers (empty calldata)
+    #[receive]
+    #[payable]
+    pub fn receive(&mut self) -> Result<(), Vec<u8>> {
+        evm::log(Deposit {
+            sender: msg::sender(),
+            value: msg::value(),
+        });
+        Ok(())
+    }
+
+    /// Accept ETH sent with calldata that matches no function
+    ///
+    /// Calls without value are rejected so unknown selectors still fail loudly.
+    #[fallback]
+    #[payable]
+    pub fn fallback(&mut self, _calldata: &[u8]) -> ArbResult {
+        let value = msg::value();
+        if value.is_zero() {
+            return Err(b"Unknown function".to_vec());
+        }
+
+        evm::log(Deposit {
+            sender: msg::sender(),
+            value,
+        });
+        Ok(Vec::new())
+    }
+
+    /// Accept ERC-721 safe transfers
+    #[selector(name = "onERC721Received")]
+    pub fn on_erc721_received(
+        &self,
+        _operator: Address,
+        _from: Address,
+        _token_id: U256,
+        _data: Bytes,
+    ) -> Result<FixedBytes<4>, Vec<u8>> {
+        Ok(FixedBytes(ERC721_RECEIVED))
+    }
+
+    /// Accept ERC-1155 single transfers
+    #[selector(name = "onERC1155Received")]
+    pub fn on_erc1155_received(
+        &self,
+        _operator: Address,
+        _from: Address,
+        _id: U256,
+        _value: U256,
+        _data: Bytes,
+    ) -> Result<FixedBytes<4>, Vec<u8>> {
+        Ok(FixedBytes(ERC1155_RECEIVED))
+    }
+
+    /// Accept ERC-1155 batch transfers
+    #[selector(name = "onERC1155BatchReceived")]
+    pub fn on_erc1155_batch_received(
+        &self,
+        _operator: Address,
+        _from: Address,
+        _ids: Vec<U256>,
+        _values: Vec<U256>,
+        _data: Bytes,
+    ) -> Result<FixedBytes<4>, Vec<u8>> {
+        Ok(FixedBytes(ERC1155_BATCH_RECEIVED))
+    }
+
+    /// ERC-165 interface detection
+    #[selector(name = "supportsInterface")]
+    pub fn supports_interface(&self, interface_id: FixedBytes<4>) -> Result<bool, Vec<u8>> {
+        let id = interface_id.0;
+        Ok(id == ERC165_INTERFACE_ID
+            || id == ERC721_RECEIVED
+            || id == ERC1155_RECEIVER_INTERFACE_ID
+            || id == ERC1271_MAGIC_VALUE)
+    }
+
+    /// Get wallet balance
+    pub fn get_balance(&self) -> Result<U256, Vec<u8>> {
+        Ok(self.balance())
+    }
+}
+
+/// Internal helper methods
+impl MultisigWallet {
+    /// Require that caller is an owner
+    fn require_owner(&self) -> Result<(), Vec<u8>> {
+        if !self.is_owner.get(msg::sender()) {
+            return Err(b"Not an owner".to_vec());
+        }
+        Ok(())
+    }
+
+    /// Require that caller is the wallet itself (for owner management)
+    fn require_wallet(&self) -> Result<(), Vec<u8>> {
+        if msg::sender() != self.address() {
+            return Err(b"Only wallet can call this".to_vec());
+        }
+        Ok(())
+    }
+
+    /// Require that caller is a guardian
+    fn require_guardian(&self) -> Result<(), Vec<u8>> {
+        if !self.is_guardian.get(msg::sender()) {
+            return Err(b"Not a guardian".to_vec());
+        }
+        Ok(())
+    }
+
+    /// Require that recovery exists and can still be approved or executed
+    fn require_recovery_pending(&self, recovery_id: U256) -> Result<(), Vec<u8>> {
+        let recovery = self.get_recovery_ref(recovery_id)?;
+        if recovery.executed.get() {
+            return Err(b"Recovery already executed".to_vec());
+        }
+        if recovery.vetoed.get() {
+            return Err(b"Recovery vetoed".to_vec());
+        }
+        if recovery.recovery_nonce.get() != self.recovery_nonce.get() {
+            return Err(b"Recovery superseded".to_vec());
+        }
+        Ok(())
+    }
+
+    /// Validate a proposed owner set the same way `initialize` does
+    fn validate_owner_set(
+        &self,
+        owners: &[Address],
+        weights: &[U256],
+        required: U256,
+    ) -> Result<(), Vec<u8>> {
+        if owners.is_empty() {
+            return Err(b"Owners required".to_vec());
+        }
+
+        if owners.len() != weights.len() {
+            return Err(b"Owner and weight length mismatch".to_vec());
+        }
+
+        let mut total_weight = U256::ZERO;
+        for (i, (owner, weight)) in owners.iter().zip(weights.iter()).enumerate() {
+            if *owner == Address::ZERO {
+                return Err(b"Invalid owner address".to_vec());
+            }
+
+            if owners[..i].contains(owner) {
+                return Err(b"Duplicate owner".to_vec());
+            }
+
+            if weight.is_zero() {
+                return Err(b"Invalid owner weight".to_vec());
+            }
+
+            total_weight = self.add_weight(total_weight, *weight)?;
+        }
+
+        if required.is_zero() || required > total_weight {
+            return Err(b"Invalid required confirmations".to_vec());
+        }
+        Ok(())
+    }
+
+    /// Record the caller's approval and start the recovery delay once the
+    /// guardian threshold is reached
+    fn _confirm_recovery(&mut self, recovery_id: U256) -> Result<(), Vec<u8>> {
+        let sender = msg::sender();
+
+        {
+            let recovery = self.get_recovery_mut(recovery_id)?;
+            if recovery.approvals.get(sender) {
+                return Err(b"Recovery already confirmed".to_vec());
+            }
+            recovery.approvals.setter(sender).set(true);
+        }
+
+        evm::log(RecoveryConfirmed {
+            recoveryId: recovery_id,
+            guardian: sender,
+        });
+
+        let (approvals, ready_at) = {
+            let recovery = self.get_recovery_ref(recovery_id)?;
+            (self.recovery_approvals(recovery), recovery.ready_at.get())
+        };
+
+        if approvals >= self.guardian_threshold.get() && ready_at.is_zero() {
+            let ready_at = self.current_timestamp() + self.recovery_delay.get();
+            let recovery = self.get_recovery_mut(recovery_id)?;
+            recovery.ready_at.set(ready_at);
+
+            evm::log(RecoveryReady {
+                recoveryId: recovery_id,
+                readyAt: ready_at,
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Require a recovery delay long enough for owners to veto
+    fn require_min_recovery_delay(&self) -> Result<(), Vec<u8>> {
+        if self.recovery_delay.get() < U256::from(MIN_RECOVERY_DELAY) {
+            return Err(b"Recovery delay too short".to_vec());
+        }
+        Ok(())
+    }
+
+    /// Total weight of current owners who have voted to cancel a transaction
+    fn cancellation_weight(&self, tx: &Transaction) -> Result<U256, Vec<u8>> {
+        let mut cancellation_weight = U256::ZERO;
+        for i in 0..self.owners.len() {
+            if let Some(owner) = self.owners.get(i) {
+                if tx.cancellations.get(owner) {
+                    cancellation_weight =
+                        self.add_weight(cancellation_weight, self.owner_weights.get(owner))?;
+                }
+            }
+        }
+        Ok(cancellation_weight)
+    }
+
+    /// Total weight of current owners who have vetoed a recovery
+    fn recovery_veto_weight(&self, recovery: &RecoveryRequest) -> Result<U256, Vec<u8>> {
+        let mut veto_weight = U256::ZERO;
+        for i in 0..self.owners.len() {
+            if let Some(owner) = self.owners.get(i) {
+                if recovery.vetoes.get(owner) {
+                    veto_weight = self.add_weight(veto_weight, self.owner_weights.get(owner))?;
+                }
+            }
+        }
+        Ok(veto_weight)
+    }
+
+    /// Count approvals from current guardians
+    fn recovery_approvals(&self, recovery: &RecoveryRequest) -> U256 {
+        let mut approvals = U256::ZERO;
+        for i in 0..self.guardians.len() {
+            if let Some(guardian) = self.guardians.get(i) {
+                if recovery.approvals.get(guardian) {
+                    approvals += U256::from(1);
+                }
+            }
+        }
+        approvals
+    }
+
+    /// Require that transaction exists and is not executed
+    fn require_not_executed(&self, tx_id: U256) -> Result<(), Vec<u8>> {
+        let tx = self.get_transaction_ref(tx_id)?;
+        if tx.executed.get() {
+            return Err(b"Transaction already executed".to_vec());
+        }
+        Ok(())
+    }
+
+    /// Require that transaction is still pending (not executed, expired or cancelled)
+    fn require_pending(&self, tx_id: U256) -> Result<(), Vec<u8>> {
+        match self.transaction_status(tx_id)? {
+            TransactionStatus::Pending => Ok(()),
+            TransactionStatus::Executed => Err(b"Transaction already executed".to_vec()),
+            TransactionStatus::Expired => Err(b"Transaction expired".to_vec()),
+            TransactionStatus::Cancelled => Err(b"Transaction cancelled".to_vec()),
+        }
+    }
+
+    /// Resolve the current status of a transaction
+    fn transaction_status(&self, tx_id: U256) -> Result<TransactionStatus, Vec<u8>> {
+        let tx = self.get_transaction_ref(tx_id)?;
+
+        if tx.executed.get() {
+            return Ok(TransactionStatus::Executed);
+        }
+
+        if tx.cancelled.get() {
+            return Ok(TransactionStatus::Cancelled);
+        }
+
+        let valid_until = tx.valid_until.get();
+        if tx.expired.get() || (!valid_until.is_zero() && self.current_timestamp() > valid_until) {
+            return Ok(TransactionStatus::Expired);
+        }
+
+        Ok(TransactionStatus::Pending)
+    }
+
+    /// Let the guard veto a call before it is made
+    fn check_guard_before(
+        &mut self,
+        guard: Address,
+        to: Address,
+        value: U256,
+        data: &[u8],
+        executor: Address,
+    ) -> Result<(), Vec<u8>> {
+        if guard == Address::ZERO || self.is_set_guard_call(to, data) {
+            return Ok(());
+        }
+
+        ITransactionGuard::new(guard)
+            .check_transaction(Call::new_in(self), to, value, data.to_vec().into(), executor)
+            .map_err(|_| b"Transaction rejected by guard".to_vec())
+    }
+
+    /// Let the guard assert post-conditions after a call succeeds
+    fn check_guard_after(
+        &mut self,
+        guard: Address,
+        to: Address,
+        value: U256,
+        data: &[u8],
+        executor: Address,
+    ) -> Result<(), Vec<u8>> {
+        if guard == Address::ZERO || self.is_set_guard_call(to, data) {
+            return Ok(());
+        }
+
+        ITransactionGuard::new(guard)
+            .check_after_execution(Call::new_in(self), to, value, data.to_vec().into(), executor)
+            .map_err(|_| b"Guard post-condition failed".to_vec())
+    }
+
+    /// Check whether a call is a self-call to `set_guard`, which the guard cannot block
+    fn is_set_guard_call(&self, to: Address, data: &[u8]) -> bool {
+        to == self.address()
+            && data.len() >= 4
+            && data[..4] == function_selector!("setGuard", Address)[..]
+    }
+
+    /// Require that transaction is confirmed
+    ///
+    /// Confirmations are recounted against the current owner set, so
+    /// confirmations from removed or replaced owners do not count.
+    fn require_confirmed(&self, tx_id: U256) -> Result<(), Vec<u8>> {
+        let tx = self.get_transaction_ref(tx_id)?;
+        if !self.is_confirmed(tx)? {
+            return Err(b"Transaction not confirmed".to_vec());
+        }
+        Ok(())
+    }
+
+    /// Require that the execution delay has passed since final confirmation
+    fn require_delay_passed(&self, tx_id: U256) -> Result<(), Vec<u8>> {
+        if self.execution_delay.get().is_zero() {
+            return Ok(());
+        }
+
+        let tx = self.get_transaction_ref(tx_id)?;
+        if !self.delay_started(tx) {
+            return Err(b"Execution delay not started".to_vec());
+        }
+
+        let ready_at = tx.ready_at.get();
+        if self.current_timestamp() < ready_at {
+            return Err(b"Execution delay not passed".to_vec());
+        }
+        Ok(())
+    }
+
+    /// Check whether a transaction's execution delay was started under the current configuration
+    fn delay_started(&self, tx: &Transaction) -> bool {
+        !tx.ready_at.get().is_zero() && tx.ready_nonce.get() == self.config_nonce.get()
+    }
+
+    /// Invalidate execution delays started under the current configuration
+    fn bump_config_nonce(&mut self) {
+        self.config_nonce.set(self.config_nonce.get() + U256::from(1));
+    }
+
+    /// Check whether a transaction has enough confirmation weight from current owners
+    fn is_confirmed(&self, tx: &Transaction) -> Result<bool, Vec<u8>> {
+        Ok(self.confirmation_weight(tx)? >= self.required_weight(tx))
+    }
+
+    /// Confirmation weight a transaction needs: the highest tier among its calls
+    fn required_weight(&self, tx: &Transaction) -> U256 {
+        // The transfer cap applies to the total value, so a batch cannot split
+        // a large transfer into small ones
+        let total_value = tx.value.get();
+
+        self.transaction_calls(tx)
+            .iter()
+            .map(|(to, value, data)| self.call_required_weight(*to, *value, total_value, data))
+            .max()
+            .unwrap_or_else(|| self.required_confirmations.get())
+    }
+
+    /// Confirmation weight a single call needs under the current policy
+    fn call_required_weight(&self, to: Address, value: U256, total_value: U256, data: &[u8]) -> U256 {
+        let threshold = if to == self.address() {
+            if self.is_owner_management_call(data) {
+                self.owner_management_threshold.get()
+            } else {
+                U256::ZERO
+            }
+        } else if data.is_empty() && !value.is_zero() && total_value <= self.transfer_cap.get() {
+            self.transfer_threshold.get()
+        } else {
+            self.call_threshold.get()
+        };
+
+        if threshold.is_zero() {
+            self.required_confirmations.get()
+        } else {
+            threshold
+        }
+    }
+
+    /// Check whether calldata targets a function that changes who controls the wallet
+    fn is_owner_management_call(&self, data: &[u8]) -> bool {
+        if data.len() < 4 {
+            return false;
+        }
+
+        let selectors: [[u8; 4]; 13] = [
+            function_selector!("addOwner", Address, U256),
+            function_selector!("removeOwner", Address),
+            function_selector!("replaceOwner", Address, Address),
+            function_selector!("setOwnerWeight", Address, U256),
+            function_selector!("changeRequirement", U256),
+            function_selector!("setConfirmationPolicy", U256, U256, U256, U256),
+            function_selector!("addGuardian", Address),
+            function_selector!("removeGuardian", Address),
+            function_selector!("changeGuardianThreshold", U256),
+            function_selector!("setRecoveryDelay", U256),
+            function_selector!("enableModule", Address),
+            function_selector!("disableModule", Address),
+            function_selector!("setGuard", Address),
+        ];
+
+        selectors.iter().any(|selector| data[..4] == selector[..])
+    }
+
+    /// Highest confirmation weight any transaction can need
+    fn highest_threshold(&self) -> U256 {
+        self.required_confirmations
+            .get()
+            .max(self.transfer_threshold.get())
+            .max(self.call_threshold.get())
+            .max(self.owner_management_threshold.get())
+    }
+
+    /// Total weight of current owners who have confirmed a transaction
+    fn confirmation_weight(&self, tx: &Transaction) -> Result<U256, Vec<u8>> {
+        self.current_confirmations(tx)
+            .iter()
+            .try_fold(U256::ZERO, |total, owner| self.add_weight(total, self.owner_weights.get(*owner)))
+    }
+
+    /// Add two owner weights, failing instead of wrapping on overflow
+    fn add_weight(&self, total: U256, weight: U256) -> Result<U256, Vec<u8>> {
+        total.checked_add(weight).ok_or_else(|| b"Weight overflow".to_vec())
+    }
+
+    /// Start the execution delay when a transaction becomes fully confirmed
+    /// (or restart it if the configuration changed since it started), and
+    /// reset it if a revocation drops it below the threshold
+    fn update_ready_at(&mut self, tx_id: U256) -> Result<(), Vec<u8>> {
+        let (confirmed, started, ready_at) = {
+            let tx = self.get_transaction_ref(tx_id)?;
+            (self.is_confirmed(tx)?, self.delay_started(tx), tx.ready_at.get())
+        };
+
+        if confirmed && !started {
+            let ready_at = self.current_timestamp() + self.execution_delay.get();
+            let config_nonce = self.config_nonce.get();
+            let tx = self.get_transaction_mut(tx_id)?;
+            tx.ready_at.set(ready_at);
+            tx.ready_nonce.set(config_nonce);
+
+            evm::log(TransactionReady {
+                txId: tx_id,
+                readyAt: ready_at,
+            });
+        } else if !confirmed && !ready_at.is_zero() {
+            let tx = self.get_transaction_mut(tx_id)?;
+            tx.ready_at.set(U256::ZERO);
+        }
+
+        Ok(())
+    }
+
+    /// Current owners who have confirmed a transaction
+    fn current_confirmations(&self, tx: &Transaction) -> Vec<Address> {
+        let mut confirmations = Vec::new();
+        for i in 0..self.owners.len() {
+            if let Some(owner) = self.owners.get(i) {
+                if tx.confirmations.get(owner) {
+                    confirmations.push(owner);
+                }
+            }
+        }
+        confirmations
+    }
+
+    /// Internal method to store a new transaction
+    fn _create_transaction(
+        &mut self,
+        kind: TransactionKind,
+        to: Address,
+        value: U256,
+        data: &[u8],
+        valid_until: U256,
+        gas_limit: U256,
+    ) -> Result<U256, Vec<u8>> {
+        if !valid_until.is_zero() && valid_until < self.current_timestamp() {
+            return Err(b"Deadline already passed".to_vec());
+        }
+
+        if gas_limit > U256::from(u64::MAX) {
+            return Err(b"Invalid gas limit".to_vec());
+        }
+
+        // Create new transaction
+        let tx_id = U256::from(self.transactions.len());
+        let mut new_tx = self.transactions.grow();
+
+        new_tx.kind.set(U8::from(kind as u8));
+        new_tx.destination.set(to);
+        new_tx.value.set(value);
+
+        // Store data
+        for byte in data.iter() {
+            new_tx.data.push(*byte);
+        }
+
+        new_tx.executed.set(false);
+        new_tx.confirmation_count.set(U256::ZERO);
+        new_tx.valid_until.set(valid_until);
+        new_tx.expired.set(false);
+        new_tx.submitter.set(msg::sender());
+        new_tx.cancelled.set(false);
+        new_tx.ready_at.set(U256::ZERO);
+        new_tx.ready_nonce.set(U256::ZERO);
+        new_tx.return_data_hash.set(B256::ZERO);
+        new_tx.gas_limit.set(gas_limit);
+
+        evm::log(TransactionSubmitted {
+            txId: tx_id,
+            submitter: msg::sender(),
+            to,
+            value,
+        });
+
+        Ok(tx_id)
+    }
+
+    /// Read the (destination, value, data) calls a transaction makes
+    fn transaction_calls(&self, tx: &Transaction) -> Vec<(Address, U256, Vec<u8>)> {
+        let mut calls = Vec::new();
+
+        if tx.kind.get().to::<u8>() == TransactionKind::Batch as u8 {
+            for i in 0..tx.calls.len() {
+                if let Some(batch_call) = tx.calls.get(i) {
+                    calls.push((
+                        batch_call.destination.get(),
+                        batch_call.value.get(),
+                        self.read_bytes(&batch_call.data),
+                    ));
+                }
+            }
+        } else {
+            calls.push((tx.destination.get(), tx.value.get(), self.read_bytes(&tx.data)));
+        }
+
+        calls
+    }
+
+    /// Deploy the contract of a Create or Create2 transaction already marked executed
+    fn execute_deployment(
+        &mut self,
+        tx_id: U256,
+        guard: Address,
+        executor: Address,
+    ) -> Result<(), Vec<u8>> {
+        let (kind, value, init_code, salt) = {
+            let tx = self.get_transaction_ref(tx_id)?;
+            (
+                tx.kind.get().to::<u8>(),
+                tx.value.get(),
+                self.read_bytes(&tx.data),
+                tx.salt.get(),
+            )
+        };
+
+        self.check_guard_before(guard, Address::ZERO, value, &init_code, executor)?;
+
+        let mut deployer = RawDeploy::new().flush_storage_cache();
+        if kind == TransactionKind::Create2 as u8 {
+            deployer = deployer.salt(salt);
+        }
+
+        let deployed = unsafe { deployer.deploy(&init_code, value) }
+            .map_err(|revert_data| self.execution_error(kind, 0, revert_data))?;
+
+        self.check_guard_after(guard, Address::ZERO, value, &init_code, executor)?;
+
+        let return_data_hash = keccak(deployed.abi_encode());
+        {
+            let tx = self.get_transaction_mut(tx_id)?;
+            tx.deployed.set(deployed);
+            tx.return_data_hash.set(return_data_hash);
+        }
+
+        evm::log(ContractDeployed {
+            txId: tx_id,
+            deployed,
+        });
+        evm::log(TransactionExecuted {
+            txId: tx_id,
+            executor,
+            returnDataHash: return_data_hash,
+        });
+
+        Ok(())
+    }
+
+    /// Require enough gas left that a call receives its full gas limit
+    ///
+    /// Only 63/64 of the remaining gas can be forwarded, so an executor could
+    /// otherwise starve the call to make it fail on purpose.
+    fn require_gas_for_call(&self, gas_limit: u64) -> Result<(), Vec<u8>> {
+        let required = gas_limit.saturating_mul(64) / 63 + CALL_GAS_BUFFER;
+        if evm::gas_left() < required {
+            return Err(b"Not enough gas for transaction".to_vec());
+        }
+        Ok(())
+    }
+
+    /// Build the error returned when a call reverts, bubbling up its revert data
+    fn execution_error(&self, kind: u8, index: usize, revert_data: Vec<u8>) -> Vec<u8> {
+        if kind == TransactionKind::Batch as u8 {
+            let error = BatchCallFailed {
+                index: U256::from(index),
+                reason: revert_data.into(),
+            };
+            return SolError::abi_encode(&error);
+        }
+
+        if revert_data.is_empty() {
+            return b"Transaction execution failed".to_vec();
+        }
+        revert_data
+    }
+
+    /// Read a byte vector from storage
+    fn read_bytes(&self, bytes: &StorageVec<u8>) -> Vec<u8> {
+        let mut result = Vec::new();
+        for i in 0..bytes.len() {
+            if let Some(byte) = bytes.get(i) {
+                result.push(byte);
+            }
+        }
+        result
+    }
+
+    /// Internal method to confirm a transaction
+    fn _confirm_transaction(&mut self, tx_id: U256) -> Result<(), Vec<u8>> {
+        self.require_pending(tx_id)?;
+
+        let sender = msg::sender();
+
+        // Check if already confirmed
+        let tx = self.get_transaction_mut(tx_id)?;
+        if tx.confirmations.get(sender) {
+            return Err(b"Transaction already confirmed".to_vec());
+        }
+
+        // Add confirmation
+        tx.confirmations.setter(sender).set(true);
+        let current_count = tx.confirmation_count.get();
+        tx.confirmation_count.set(current_count + U256::from(1));
+
+        evm::log(TransactionConfirmed {
+            txId: tx_id,
+            owner: sender,
+        });
+
+        self.update_ready_at(tx_id)?;
+
+        Ok(())
+    }
+
+    /// EIP-712 domain separator bound to the chain ID and wallet address
+    fn eip712_domain_separator(&self) -> B256 {
+        let encoded = (keccak(DOMAIN_TYPE), U256::from(block::chainid()), self.address()).abi_encode();
+        keccak(encoded)
+    }
+
+    /// EIP-712 digest of a signed transaction
+    fn transaction_hash(&self, to: Address, value: U256, data: &[u8], nonce: U256) -> B256 {
+        let struct_hash = keccak((keccak(TRANSACTION_TYPE), to, value, keccak(data), nonce).abi_encode());
+
+        let mut digest = Vec::with_capacity(66);
+        digest.extend_from_slice(&[0x19, 0x01]);
+        digest.extend_from_slice(self.eip712_domain_separator().as_slice());
+        digest.extend_from_slice(struct_hash.as_slice());
+        keccak(digest)
+    }
+
+    /// EIP-712 digest of a message signed on behalf of the wallet
+    ///
+    /// Wrapping the hash in the wallet's domain stops owner signatures for one
+    /// wallet from being replayed against another wallet they also own.
+    fn message_hash(&self, hash: B256) -> B256 {
+        let struct_hash = keccak((keccak(MESSAGE_TYPE), hash).abi_encode());
+
+        let mut digest = Vec::with_capacity(66);
+        digest.extend_from_slice(&[0x19, 0x01]);
+        digest.extend_from_slice(self.eip712_domain_separator().as_slice());
+        digest.extend_from_slice(struct_hash.as_slice());
+        keccak(digest)
+    }
+
+    /// Verify that `signatures` holds distinct owner signatures over `hash`
+    /// with at least `required` total weight
+    fn check_signatures(&self, hash: B256, signatures: &[u8], required: U256) -> Result<(), Vec<u8>> {
+        if signatures.is_empty() || signatures.len() % SIGNATURE_LENGTH != 0 {
+            return Err(b"Invalid signatures length".to_vec());
+        }
+
+        let mut last_signer = Address::ZERO;
+        let mut valid_weight = U256::ZERO;
+
+        for signature in signatures.chunks(SIGNATURE_LENGTH) {
+            let signer = self.recover_signer(hash, signature)?;
+
+            // Ascending order guarantees every signer is counted once
+            if signer <= last_signer {
+                return Err(b"Signatures not sorted or duplicated".to_vec());
+            }
+
+            if !self.is_owner.get(signer) {
+                return Err(b"Signer is not an owner".to_vec());
+            }
+
+            last_signer = signer;
+            valid_weight = self.add_weight(valid_weight, self.owner_weights.get(signer))?;
+        }
+
+        if valid_weight < required {
+            return Err(b"Not enough signatures".to_vec());
+        }
+        Ok(())
+    }
+
+    /// Recover the signer of a 65-byte signature via the ecrecover precompile
+    fn recover_signer(&self, hash: B256, signature: &[u8]) -> Result<Address, Vec<u8>> {
+        let r = &signature[0..32];
+        let s = &signature[32..64];
+        let mut v = signature[64];
+        if v < 27 {
+            v += 27;
+        }
+
+        if (v != 27 && v != 28) || U256::from_be_slice(s) > SECP256K1_HALF_N {
+            return Err(b"Invalid signature".to_vec());
+        }
+
+        let mut input = Vec::with_capacity(128);
+        input.extend_from_slice(hash.as_slice());
+        input.extend_from_slice(&[0u8; 31]);
+        input.push(v);
+        input.extend_from_slice(r);
+        input.extend_from_slice(s);
+
+        let ecrecover = Address::with_last_byte(1);
+        let output = static_call(Call::new(), ecrecover, &input)
+            .map_err(|_| b"Signature recovery failed".to_vec())?;
+
+        if output.len() != 32 {
+            return Err(b"Invalid signature".to_vec());
+        }
+
+        let signer = Address::from_slice(&output[12..32]);
+        if signer == Address::ZERO {
+            return Err(b"Invalid signature".to_vec());
+        }
+        Ok(signer)
+    }
+
+    /// Get transaction reference (immutable)
+    fn get_transaction_ref(&self, tx_id: U256) -> Result<&Transaction, Vec<u8>> {
+        let index = tx_id.to::<usize>();
+        if index >= self.transactions.len() {
+            return Err(b"Transaction does not exist".to_vec());
+        }
+        Ok(self.transactions.getter(index).unwrap())
+    }
+
+    /// Get transaction reference (mutable)
+    fn get_transaction_mut(&mut self, tx_id: U256) -> Result<&mut Transaction, Vec<u8>> {
+        let index = tx_id.to::<usize>();
+        if index >= self.transactions.len() {
+            return Err(b"Transaction does not exist".to_vec());
+        }
+        Ok(self.transactions.setter(index).unwrap())
+    }
+
+    /// Send ETH (zero token address) or ERC-20 tokens from the wallet
+    fn send_funds(&mut self, token: Address, to: Address, amount: U256) -> Result<(), Vec<u8>> {
+        if token == Address::ZERO {
+            let call_result = unsafe {
+                call(
+                    Call::new_in(self)
+                        .value(amount),
+                    to,
+                    &[],
+                )
+            };
+
+            if call_result.is_err() {
+                return Err(b"ETH transfer failed".to_vec());
+            }
+        } else {
+            let mut data = function_selector!("transfer", Address, U256).to_vec();
+            data.extend_from_slice(&(to, amount).abi_encode_params());
+
+            let return_data = unsafe { call(Call::new_in(self), token, &data) }
+                .map_err(|_| b"Token transfer failed".to_vec())?;
+
+            // Tokens like USDT return nothing; others must return true
+            let succeeded = if return_data.is_empty() {
+                token.has_code()
+            } else {
+                return_data.len() >= 32 && U256::from_be_slice(&return_data[..32]) == U256::from(1)
+            };
+
+            if !succeeded {
+                return Err(b"Token transfer failed".to_vec());
+            }
+        }
+        Ok(())
+    }
+
+    /// Get payment schedule reference (immutable)
+    fn get_payment_schedule_ref(&self, schedule_id: U256) -> Result<&PaymentSchedule, Vec<u8>> {
+        let index = schedule_id.to::<usize>();
+        if index >= self.payment_schedules.len() {
+            return Err(b"Schedule does not exist".to_vec());
+        }
+        Ok(self.payment_schedules.getter(index).unwrap())
+    }
+
+    /// Get payment schedule reference (mutable)
+    fn get_payment_schedule_mut(&mut self, schedule_id: U256) -> Result<&mut PaymentSchedule, Vec<u8>> {
+        let index = schedule_id.to::<usize>();
+        if index >= self.payment_schedules.len() {
+            return Err(b"Schedule does not exist".to_vec());
+        }
+        Ok(self.payment_schedules.setter(index).unwrap())
+    }
+
+    /// Get recovery reference (immutable)
+    fn get_recovery_ref(&self, recovery_id: U256) -> Result<&RecoveryRequest, Vec<u8>> {
+        let index = recovery_id.to::<usize>();
+        if index >= self.recoveries.len() {
+            return Err(b"Recovery does not exist".to_vec());
+        }
+        Ok(self.recoveries.getter(index).unwrap())
+    }
+
+    /// Get recovery reference (mutable)
+    fn get_recovery_mut(&mut self, recovery_id: U256) -> Result<&mut RecoveryRequest, Vec<u8>> {
+        let index = recovery_id.to::<usize>();
+        if index >= self.recoveries.len() {
+            return Err(b"Recovery does not exist".to_vec());
+        }
+        Ok(self.recoveries.setter(index).unwrap())
+    }
+
+    /// Get contract balance
+    fn balance(&self) -> U256 {
+        U256::from(self.address().balance())
+    }
+
+    /// Get contract address
+    fn address(&self) -> Address {
+        Address::from(evm::contract_address().0)
+    }
+
+    /// Get current timestamp
+    fn current_timestamp(&self) -> U256 {
+        U256::from(evm::block_timestamp())
     }
 }